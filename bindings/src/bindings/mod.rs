@@ -4,37 +4,279 @@
 #![allow(unused, clippy::all)]
 use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 
+pub mod abilities_cooldowns_table;
+pub mod abilities_table;
+pub mod ability_cooldown_type;
+pub mod ability_type;
+pub mod account_type;
+pub mod accounts_table;
+pub mod add_admin_reducer;
+pub mod admin_type;
+pub mod admins_table;
+pub mod autosave_players_reducer;
+pub mod cast_ability_on_npc_reducer;
+pub mod cast_ability_reducer;
+pub mod character_class_type;
+pub mod character_type;
+pub mod characters_table;
+pub mod chat_channel_type;
+pub mod chat_cleanup_type;
+pub mod chat_cleanups_table;
+pub mod chat_message_type;
+pub mod chat_messages_table;
+pub mod cleanup_chat_messages_reducer;
+pub mod consistency_check_type;
+pub mod consistency_checks_table;
+pub mod create_character_reducer;
+pub mod create_spawn_region_reducer;
+pub mod delete_character_reducer;
+pub mod delete_spawn_region_reducer;
+pub mod despawn_npc_reducer;
+pub mod despawn_world_items_reducer;
+pub mod drop_item_reducer;
+pub mod equip_item_reducer;
+pub mod equipment_slot_type;
+pub mod equipped_item_type;
+pub mod equipped_items_table;
+pub mod flush_positions_lods_reducer;
+pub mod inventory_slot_type;
+pub mod inventory_slots_table;
+pub mod item_definition_type;
+pub mod item_definitions_table;
+pub mod lod_ring_type;
+pub mod lod_rings_table;
+pub mod move_item_reducer;
 pub mod move_player_reducer;
+pub mod movement_state_type;
+pub mod movement_violation_type;
+pub mod movement_violations_table;
+pub mod npc_ai_tick_type;
+pub mod npc_behavior_type;
+pub mod npc_position_lod_type;
+pub mod npc_position_type;
+pub mod npc_respawn_type;
+pub mod npc_type;
+pub mod npc_type_type;
+pub mod npc_types_table;
+pub mod npcs_ai_ticks_table;
+pub mod npcs_positions_lods_table;
+pub mod npcs_positions_table;
+pub mod npcs_respawns_table;
+pub mod npcs_table;
 pub mod on_connected_reducer;
 pub mod on_disconnected_reducer;
+pub mod pick_up_item_reducer;
+pub mod player_autosave_type;
+pub mod player_input_ack_type;
+pub mod player_input_type;
+pub mod player_position_lod_flush_type;
+pub mod player_position_lod_type;
 pub mod player_position_type;
+pub mod player_regen_tick_type;
+pub mod player_respawn_type;
+pub mod player_simulation_tick_type;
 pub mod player_type;
+pub mod player_window_cell_type;
 pub mod player_window_type;
 pub mod player_window_update_type;
-pub mod players_positions_lr_table;
+pub mod players_autosaves_table;
+pub mod players_inputs_acks_table;
+pub mod players_inputs_table;
+pub mod players_lods_flushes_table;
+pub mod players_positions_lods_table;
 pub mod players_positions_table;
+pub mod players_regen_ticks_table;
+pub mod players_respawns_table;
+pub mod players_simulation_ticks_table;
 pub mod players_table;
 pub mod players_window_updates_table;
+pub mod players_windows_cells_table;
 pub mod players_windows_table;
+pub mod populate_spawn_regions_reducer;
+pub mod regenerate_players_reducer;
+pub mod report_consistency_reducer;
+pub mod respawn_player_reducer;
+pub mod select_character_reducer;
+pub mod send_message_reducer;
+pub mod server_config_table;
+pub mod server_config_type;
+pub mod session_type;
+pub mod sessions_table;
+pub mod set_autosave_interval_reducer;
+pub mod set_view_distance_reducer;
+pub mod simulate_players_reducer;
+pub mod spawn_npc_reducer;
+pub mod spawn_region_tick_type;
+pub mod spawn_region_type;
+pub mod spawn_regions_table;
+pub mod spawn_regions_ticks_table;
+pub mod split_stack_reducer;
+pub mod submit_input_reducer;
+pub mod tick_npcs_reducer;
+pub mod unequip_item_reducer;
 pub mod update_players_windows_reducer;
-
+pub mod update_spawn_region_reducer;
+pub mod world_item_despawn_type;
+pub mod world_item_type;
+pub mod world_items_despawns_table;
+pub mod world_items_table;
+
+pub use abilities_cooldowns_table::*;
+pub use abilities_table::*;
+pub use ability_cooldown_type::AbilityCooldown;
+pub use ability_type::Ability;
+pub use account_type::Account;
+pub use accounts_table::*;
+pub use add_admin_reducer::{add_admin, set_flags_for_add_admin, AddAdminCallbackId};
+pub use admin_type::Admin;
+pub use admins_table::*;
+pub use autosave_players_reducer::{
+    autosave_players, set_flags_for_autosave_players, AutosavePlayersCallbackId,
+};
+pub use cast_ability_on_npc_reducer::{
+    cast_ability_on_npc, set_flags_for_cast_ability_on_npc, CastAbilityOnNpcCallbackId,
+};
+pub use cast_ability_reducer::{cast_ability, set_flags_for_cast_ability, CastAbilityCallbackId};
+pub use character_class_type::CharacterClass;
+pub use character_type::Character;
+pub use characters_table::*;
+pub use chat_channel_type::ChatChannel;
+pub use chat_cleanup_type::ChatCleanup;
+pub use chat_cleanups_table::*;
+pub use chat_message_type::ChatMessage;
+pub use chat_messages_table::*;
+pub use cleanup_chat_messages_reducer::{
+    cleanup_chat_messages, set_flags_for_cleanup_chat_messages, CleanupChatMessagesCallbackId,
+};
+pub use consistency_check_type::ConsistencyCheck;
+pub use consistency_checks_table::*;
+pub use create_character_reducer::{
+    create_character, set_flags_for_create_character, CreateCharacterCallbackId,
+};
+pub use create_spawn_region_reducer::{
+    create_spawn_region, set_flags_for_create_spawn_region, CreateSpawnRegionCallbackId,
+};
+pub use delete_character_reducer::{
+    delete_character, set_flags_for_delete_character, DeleteCharacterCallbackId,
+};
+pub use delete_spawn_region_reducer::{
+    delete_spawn_region, set_flags_for_delete_spawn_region, DeleteSpawnRegionCallbackId,
+};
+pub use despawn_npc_reducer::{despawn_npc, set_flags_for_despawn_npc, DespawnNpcCallbackId};
+pub use despawn_world_items_reducer::{
+    despawn_world_items, set_flags_for_despawn_world_items, DespawnWorldItemsCallbackId,
+};
+pub use drop_item_reducer::{drop_item, set_flags_for_drop_item, DropItemCallbackId};
+pub use equip_item_reducer::{equip_item, set_flags_for_equip_item, EquipItemCallbackId};
+pub use equipment_slot_type::EquipmentSlot;
+pub use equipped_item_type::EquippedItem;
+pub use equipped_items_table::*;
+pub use flush_positions_lods_reducer::{
+    flush_positions_lods, set_flags_for_flush_positions_lods, FlushPositionsLodsCallbackId,
+};
+pub use inventory_slot_type::InventorySlot;
+pub use inventory_slots_table::*;
+pub use item_definition_type::ItemDefinition;
+pub use item_definitions_table::*;
+pub use lod_ring_type::LodRing;
+pub use lod_rings_table::*;
+pub use move_item_reducer::{move_item, set_flags_for_move_item, MoveItemCallbackId};
 pub use move_player_reducer::{move_player, set_flags_for_move_player, MovePlayerCallbackId};
+pub use movement_state_type::MovementState;
+pub use movement_violation_type::MovementViolation;
+pub use movement_violations_table::*;
+pub use npc_ai_tick_type::NpcAiTick;
+pub use npc_behavior_type::NpcBehavior;
+pub use npc_position_lod_type::NpcPositionLod;
+pub use npc_position_type::NpcPosition;
+pub use npc_respawn_type::NpcRespawn;
+pub use npc_type::Npc;
+pub use npc_type_type::NpcType;
+pub use npc_types_table::*;
+pub use npcs_ai_ticks_table::*;
+pub use npcs_positions_lods_table::*;
+pub use npcs_positions_table::*;
+pub use npcs_respawns_table::*;
+pub use npcs_table::*;
 pub use on_connected_reducer::{on_connected, set_flags_for_on_connected, OnConnectedCallbackId};
 pub use on_disconnected_reducer::{
     on_disconnected, set_flags_for_on_disconnected, OnDisconnectedCallbackId,
 };
+pub use pick_up_item_reducer::{pick_up_item, set_flags_for_pick_up_item, PickUpItemCallbackId};
+pub use player_autosave_type::PlayerAutosave;
+pub use player_input_ack_type::PlayerInputAck;
+pub use player_input_type::PlayerInput;
+pub use player_position_lod_flush_type::PlayerPositionLodFlush;
+pub use player_position_lod_type::PlayerPositionLod;
 pub use player_position_type::PlayerPosition;
+pub use player_regen_tick_type::PlayerRegenTick;
+pub use player_respawn_type::PlayerRespawn;
+pub use player_simulation_tick_type::PlayerSimulationTick;
 pub use player_type::Player;
+pub use player_window_cell_type::PlayerWindowCell;
 pub use player_window_type::PlayerWindow;
 pub use player_window_update_type::PlayerWindowUpdate;
-pub use players_positions_lr_table::*;
+pub use players_autosaves_table::*;
+pub use players_inputs_acks_table::*;
+pub use players_inputs_table::*;
+pub use players_lods_flushes_table::*;
+pub use players_positions_lods_table::*;
 pub use players_positions_table::*;
+pub use players_regen_ticks_table::*;
+pub use players_respawns_table::*;
+pub use players_simulation_ticks_table::*;
 pub use players_table::*;
 pub use players_window_updates_table::*;
+pub use players_windows_cells_table::*;
 pub use players_windows_table::*;
+pub use populate_spawn_regions_reducer::{
+    populate_spawn_regions, set_flags_for_populate_spawn_regions, PopulateSpawnRegionsCallbackId,
+};
+pub use regenerate_players_reducer::{
+    regenerate_players, set_flags_for_regenerate_players, RegeneratePlayersCallbackId,
+};
+pub use report_consistency_reducer::{
+    report_consistency, set_flags_for_report_consistency, ReportConsistencyCallbackId,
+};
+pub use respawn_player_reducer::{
+    respawn_player, set_flags_for_respawn_player, RespawnPlayerCallbackId,
+};
+pub use select_character_reducer::{
+    select_character, set_flags_for_select_character, SelectCharacterCallbackId,
+};
+pub use send_message_reducer::{send_message, set_flags_for_send_message, SendMessageCallbackId};
+pub use server_config_table::*;
+pub use server_config_type::ServerConfig;
+pub use session_type::Session;
+pub use sessions_table::*;
+pub use set_autosave_interval_reducer::{
+    set_autosave_interval, set_flags_for_set_autosave_interval, SetAutosaveIntervalCallbackId,
+};
+pub use set_view_distance_reducer::{
+    set_flags_for_set_view_distance, set_view_distance, SetViewDistanceCallbackId,
+};
+pub use simulate_players_reducer::{
+    set_flags_for_simulate_players, simulate_players, SimulatePlayersCallbackId,
+};
+pub use spawn_npc_reducer::{set_flags_for_spawn_npc, spawn_npc, SpawnNpcCallbackId};
+pub use spawn_region_tick_type::SpawnRegionTick;
+pub use spawn_region_type::SpawnRegion;
+pub use spawn_regions_table::*;
+pub use spawn_regions_ticks_table::*;
+pub use split_stack_reducer::{set_flags_for_split_stack, split_stack, SplitStackCallbackId};
+pub use submit_input_reducer::{set_flags_for_submit_input, submit_input, SubmitInputCallbackId};
+pub use tick_npcs_reducer::{set_flags_for_tick_npcs, tick_npcs, TickNpcsCallbackId};
+pub use unequip_item_reducer::{set_flags_for_unequip_item, unequip_item, UnequipItemCallbackId};
 pub use update_players_windows_reducer::{
     set_flags_for_update_players_windows, update_players_windows, UpdatePlayersWindowsCallbackId,
 };
+pub use update_spawn_region_reducer::{
+    set_flags_for_update_spawn_region, update_spawn_region, UpdateSpawnRegionCallbackId,
+};
+pub use world_item_despawn_type::WorldItemDespawn;
+pub use world_item_type::WorldItem;
+pub use world_items_despawns_table::*;
+pub use world_items_table::*;
 
 #[derive(Clone, PartialEq, Debug)]
 
@@ -44,10 +286,144 @@ pub use update_players_windows_reducer::{
 /// to indicate which reducer caused the event.
 
 pub enum Reducer {
-    MovePlayer { x: f32, y: f32, z: f32 },
+    AddAdmin {
+        identity: __sdk::Identity,
+    },
+    AutosavePlayers {
+        row: PlayerAutosave,
+    },
+    CastAbility {
+        target: u64,
+        ability_id: u32,
+    },
+    CastAbilityOnNpc {
+        npc_id: u64,
+        ability_id: u32,
+    },
+    CleanupChatMessages {
+        row: ChatCleanup,
+    },
+    CreateCharacter {
+        name: String,
+        class: CharacterClass,
+    },
+    CreateSpawnRegion {
+        name: String,
+        min_x: f32,
+        min_z: f32,
+        max_x: f32,
+        max_z: f32,
+        npc_type_id: u32,
+        max_alive: u32,
+        respawn_delay_ms: u64,
+    },
+    DeleteCharacter {
+        character_id: u64,
+    },
+    DeleteSpawnRegion {
+        region_id: u64,
+    },
+    DespawnNpc {
+        npc_id: u64,
+    },
+    DespawnWorldItems {
+        row: WorldItemDespawn,
+    },
+    DropItem {
+        slot: u32,
+        quantity: u32,
+    },
+    EquipItem {
+        inventory_slot: u32,
+    },
+    FlushPositionsLods {
+        row: PlayerPositionLodFlush,
+    },
+    MoveItem {
+        from_slot: u32,
+        to_slot: u32,
+    },
+    MovePlayer {
+        x: f32,
+        y: f32,
+        z: f32,
+        yaw: f32,
+        movement: MovementState,
+        speed: f32,
+    },
     OnConnected,
     OnDisconnected,
-    UpdatePlayersWindows { row: PlayerWindowUpdate },
+    PickUpItem {
+        world_item_id: u64,
+    },
+    PopulateSpawnRegions {
+        row: SpawnRegionTick,
+    },
+    RegeneratePlayers {
+        row: PlayerRegenTick,
+    },
+    ReportConsistency {
+        row: ConsistencyCheck,
+    },
+    RespawnPlayer {
+        respawn: PlayerRespawn,
+    },
+    SelectCharacter {
+        character_id: u64,
+    },
+    SendMessage {
+        channel: ChatChannel,
+        recipient: Option<String>,
+        text: String,
+    },
+    SetAutosaveInterval {
+        interval_ms: u64,
+    },
+    SetViewDistance {
+        view_distance: f32,
+    },
+    SimulatePlayers {
+        row: PlayerSimulationTick,
+    },
+    SpawnNpc {
+        type_id: u32,
+        x: f32,
+        z: f32,
+    },
+    SplitStack {
+        from_slot: u32,
+        to_slot: u32,
+        quantity: u32,
+    },
+    SubmitInput {
+        seq: u32,
+        direction_x: f32,
+        direction_z: f32,
+        yaw: f32,
+        run: bool,
+        jump: bool,
+        dt: f32,
+    },
+    TickNpcs {
+        row: NpcAiTick,
+    },
+    UnequipItem {
+        slot: EquipmentSlot,
+    },
+    UpdatePlayersWindows {
+        row: PlayerWindowUpdate,
+    },
+    UpdateSpawnRegion {
+        region_id: u64,
+        name: String,
+        min_x: f32,
+        min_z: f32,
+        max_x: f32,
+        max_z: f32,
+        npc_type_id: u32,
+        max_alive: u32,
+        respawn_delay_ms: u64,
+    },
 }
 
 impl __sdk::InModule for Reducer {
@@ -57,10 +433,41 @@ impl __sdk::InModule for Reducer {
 impl __sdk::Reducer for Reducer {
     fn reducer_name(&self) -> &'static str {
         match self {
+            Reducer::AddAdmin { .. } => "add_admin",
+            Reducer::AutosavePlayers { .. } => "autosave_players",
+            Reducer::CastAbility { .. } => "cast_ability",
+            Reducer::CastAbilityOnNpc { .. } => "cast_ability_on_npc",
+            Reducer::CleanupChatMessages { .. } => "cleanup_chat_messages",
+            Reducer::CreateCharacter { .. } => "create_character",
+            Reducer::CreateSpawnRegion { .. } => "create_spawn_region",
+            Reducer::DeleteCharacter { .. } => "delete_character",
+            Reducer::DeleteSpawnRegion { .. } => "delete_spawn_region",
+            Reducer::DespawnNpc { .. } => "despawn_npc",
+            Reducer::DespawnWorldItems { .. } => "despawn_world_items",
+            Reducer::DropItem { .. } => "drop_item",
+            Reducer::EquipItem { .. } => "equip_item",
+            Reducer::FlushPositionsLods { .. } => "flush_positions_lods",
+            Reducer::MoveItem { .. } => "move_item",
             Reducer::MovePlayer { .. } => "move_player",
             Reducer::OnConnected => "on_connected",
             Reducer::OnDisconnected => "on_disconnected",
+            Reducer::PickUpItem { .. } => "pick_up_item",
+            Reducer::PopulateSpawnRegions { .. } => "populate_spawn_regions",
+            Reducer::RegeneratePlayers { .. } => "regenerate_players",
+            Reducer::ReportConsistency { .. } => "report_consistency",
+            Reducer::RespawnPlayer { .. } => "respawn_player",
+            Reducer::SelectCharacter { .. } => "select_character",
+            Reducer::SendMessage { .. } => "send_message",
+            Reducer::SetAutosaveInterval { .. } => "set_autosave_interval",
+            Reducer::SetViewDistance { .. } => "set_view_distance",
+            Reducer::SimulatePlayers { .. } => "simulate_players",
+            Reducer::SpawnNpc { .. } => "spawn_npc",
+            Reducer::SplitStack { .. } => "split_stack",
+            Reducer::SubmitInput { .. } => "submit_input",
+            Reducer::TickNpcs { .. } => "tick_npcs",
+            Reducer::UnequipItem { .. } => "unequip_item",
             Reducer::UpdatePlayersWindows { .. } => "update_players_windows",
+            Reducer::UpdateSpawnRegion { .. } => "update_spawn_region",
         }
     }
 }
@@ -68,6 +475,84 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
     type Error = __sdk::Error;
     fn try_from(value: __ws::ReducerCallInfo<__ws::BsatnFormat>) -> __sdk::Result<Self> {
         match &value.reducer_name[..] {
+            "add_admin" => Ok(
+                __sdk::parse_reducer_args::<add_admin_reducer::AddAdminArgs>(
+                    "add_admin",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "autosave_players" => Ok(__sdk::parse_reducer_args::<
+                autosave_players_reducer::AutosavePlayersArgs,
+            >("autosave_players", &value.args)?
+            .into()),
+            "cast_ability" => Ok(
+                __sdk::parse_reducer_args::<cast_ability_reducer::CastAbilityArgs>(
+                    "cast_ability",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "cast_ability_on_npc" => Ok(__sdk::parse_reducer_args::<
+                cast_ability_on_npc_reducer::CastAbilityOnNpcArgs,
+            >("cast_ability_on_npc", &value.args)?
+            .into()),
+            "cleanup_chat_messages" => Ok(__sdk::parse_reducer_args::<
+                cleanup_chat_messages_reducer::CleanupChatMessagesArgs,
+            >("cleanup_chat_messages", &value.args)?
+            .into()),
+            "create_character" => Ok(__sdk::parse_reducer_args::<
+                create_character_reducer::CreateCharacterArgs,
+            >("create_character", &value.args)?
+            .into()),
+            "create_spawn_region" => Ok(__sdk::parse_reducer_args::<
+                create_spawn_region_reducer::CreateSpawnRegionArgs,
+            >("create_spawn_region", &value.args)?
+            .into()),
+            "delete_character" => Ok(__sdk::parse_reducer_args::<
+                delete_character_reducer::DeleteCharacterArgs,
+            >("delete_character", &value.args)?
+            .into()),
+            "delete_spawn_region" => Ok(__sdk::parse_reducer_args::<
+                delete_spawn_region_reducer::DeleteSpawnRegionArgs,
+            >("delete_spawn_region", &value.args)?
+            .into()),
+            "despawn_npc" => Ok(
+                __sdk::parse_reducer_args::<despawn_npc_reducer::DespawnNpcArgs>(
+                    "despawn_npc",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "despawn_world_items" => Ok(__sdk::parse_reducer_args::<
+                despawn_world_items_reducer::DespawnWorldItemsArgs,
+            >("despawn_world_items", &value.args)?
+            .into()),
+            "drop_item" => Ok(
+                __sdk::parse_reducer_args::<drop_item_reducer::DropItemArgs>(
+                    "drop_item",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "equip_item" => Ok(
+                __sdk::parse_reducer_args::<equip_item_reducer::EquipItemArgs>(
+                    "equip_item",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "flush_positions_lods" => Ok(__sdk::parse_reducer_args::<
+                flush_positions_lods_reducer::FlushPositionsLodsArgs,
+            >("flush_positions_lods", &value.args)?
+            .into()),
+            "move_item" => Ok(
+                __sdk::parse_reducer_args::<move_item_reducer::MoveItemArgs>(
+                    "move_item",
+                    &value.args,
+                )?
+                .into(),
+            ),
             "move_player" => Ok(
                 __sdk::parse_reducer_args::<move_player_reducer::MovePlayerArgs>(
                     "move_player",
@@ -86,10 +571,95 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
                 on_disconnected_reducer::OnDisconnectedArgs,
             >("on_disconnected", &value.args)?
             .into()),
+            "pick_up_item" => Ok(
+                __sdk::parse_reducer_args::<pick_up_item_reducer::PickUpItemArgs>(
+                    "pick_up_item",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "populate_spawn_regions" => Ok(__sdk::parse_reducer_args::<
+                populate_spawn_regions_reducer::PopulateSpawnRegionsArgs,
+            >("populate_spawn_regions", &value.args)?
+            .into()),
+            "regenerate_players" => Ok(__sdk::parse_reducer_args::<
+                regenerate_players_reducer::RegeneratePlayersArgs,
+            >("regenerate_players", &value.args)?
+            .into()),
+            "report_consistency" => Ok(__sdk::parse_reducer_args::<
+                report_consistency_reducer::ReportConsistencyArgs,
+            >("report_consistency", &value.args)?
+            .into()),
+            "respawn_player" => Ok(__sdk::parse_reducer_args::<
+                respawn_player_reducer::RespawnPlayerArgs,
+            >("respawn_player", &value.args)?
+            .into()),
+            "select_character" => Ok(__sdk::parse_reducer_args::<
+                select_character_reducer::SelectCharacterArgs,
+            >("select_character", &value.args)?
+            .into()),
+            "send_message" => Ok(
+                __sdk::parse_reducer_args::<send_message_reducer::SendMessageArgs>(
+                    "send_message",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "set_autosave_interval" => Ok(__sdk::parse_reducer_args::<
+                set_autosave_interval_reducer::SetAutosaveIntervalArgs,
+            >("set_autosave_interval", &value.args)?
+            .into()),
+            "set_view_distance" => Ok(__sdk::parse_reducer_args::<
+                set_view_distance_reducer::SetViewDistanceArgs,
+            >("set_view_distance", &value.args)?
+            .into()),
+            "simulate_players" => Ok(__sdk::parse_reducer_args::<
+                simulate_players_reducer::SimulatePlayersArgs,
+            >("simulate_players", &value.args)?
+            .into()),
+            "spawn_npc" => Ok(
+                __sdk::parse_reducer_args::<spawn_npc_reducer::SpawnNpcArgs>(
+                    "spawn_npc",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "split_stack" => Ok(
+                __sdk::parse_reducer_args::<split_stack_reducer::SplitStackArgs>(
+                    "split_stack",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "submit_input" => Ok(
+                __sdk::parse_reducer_args::<submit_input_reducer::SubmitInputArgs>(
+                    "submit_input",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "tick_npcs" => Ok(
+                __sdk::parse_reducer_args::<tick_npcs_reducer::TickNpcsArgs>(
+                    "tick_npcs",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "unequip_item" => Ok(
+                __sdk::parse_reducer_args::<unequip_item_reducer::UnequipItemArgs>(
+                    "unequip_item",
+                    &value.args,
+                )?
+                .into(),
+            ),
             "update_players_windows" => Ok(__sdk::parse_reducer_args::<
                 update_players_windows_reducer::UpdatePlayersWindowsArgs,
             >("update_players_windows", &value.args)?
             .into()),
+            "update_spawn_region" => Ok(__sdk::parse_reducer_args::<
+                update_spawn_region_reducer::UpdateSpawnRegionArgs,
+            >("update_spawn_region", &value.args)?
+            .into()),
             unknown => {
                 Err(
                     __sdk::InternalError::unknown_name("reducer", unknown, "ReducerCallInfo")
@@ -104,11 +674,44 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
 #[allow(non_snake_case)]
 #[doc(hidden)]
 pub struct DbUpdate {
+    abilities: __sdk::TableUpdate<Ability>,
+    abilities_cooldowns: __sdk::TableUpdate<AbilityCooldown>,
+    accounts: __sdk::TableUpdate<Account>,
+    admins: __sdk::TableUpdate<Admin>,
+    characters: __sdk::TableUpdate<Character>,
+    chat_cleanups: __sdk::TableUpdate<ChatCleanup>,
+    chat_messages: __sdk::TableUpdate<ChatMessage>,
+    consistency_checks: __sdk::TableUpdate<ConsistencyCheck>,
+    equipped_items: __sdk::TableUpdate<EquippedItem>,
+    inventory_slots: __sdk::TableUpdate<InventorySlot>,
+    item_definitions: __sdk::TableUpdate<ItemDefinition>,
+    lod_rings: __sdk::TableUpdate<LodRing>,
+    movement_violations: __sdk::TableUpdate<MovementViolation>,
+    npc_types: __sdk::TableUpdate<NpcType>,
+    npcs: __sdk::TableUpdate<Npc>,
+    npcs_ai_ticks: __sdk::TableUpdate<NpcAiTick>,
+    npcs_positions: __sdk::TableUpdate<NpcPosition>,
+    npcs_positions_lods: __sdk::TableUpdate<NpcPositionLod>,
+    npcs_respawns: __sdk::TableUpdate<NpcRespawn>,
     players: __sdk::TableUpdate<Player>,
+    players_autosaves: __sdk::TableUpdate<PlayerAutosave>,
+    players_inputs: __sdk::TableUpdate<PlayerInput>,
+    players_inputs_acks: __sdk::TableUpdate<PlayerInputAck>,
+    players_lods_flushes: __sdk::TableUpdate<PlayerPositionLodFlush>,
     players_positions: __sdk::TableUpdate<PlayerPosition>,
-    players_positions_lr: __sdk::TableUpdate<PlayerPosition>,
+    players_positions_lods: __sdk::TableUpdate<PlayerPositionLod>,
+    players_regen_ticks: __sdk::TableUpdate<PlayerRegenTick>,
+    players_respawns: __sdk::TableUpdate<PlayerRespawn>,
+    players_simulation_ticks: __sdk::TableUpdate<PlayerSimulationTick>,
     players_window_updates: __sdk::TableUpdate<PlayerWindowUpdate>,
     players_windows: __sdk::TableUpdate<PlayerWindow>,
+    players_windows_cells: __sdk::TableUpdate<PlayerWindowCell>,
+    server_config: __sdk::TableUpdate<ServerConfig>,
+    sessions: __sdk::TableUpdate<Session>,
+    spawn_regions: __sdk::TableUpdate<SpawnRegion>,
+    spawn_regions_ticks: __sdk::TableUpdate<SpawnRegionTick>,
+    world_items: __sdk::TableUpdate<WorldItem>,
+    world_items_despawns: __sdk::TableUpdate<WorldItemDespawn>,
 }
 
 impl TryFrom<__ws::DatabaseUpdate<__ws::BsatnFormat>> for DbUpdate {
@@ -117,14 +720,103 @@ impl TryFrom<__ws::DatabaseUpdate<__ws::BsatnFormat>> for DbUpdate {
         let mut db_update = DbUpdate::default();
         for table_update in raw.tables {
             match &table_update.table_name[..] {
+                "abilities" => {
+                    db_update.abilities = abilities_table::parse_table_update(table_update)?
+                }
+                "abilities_cooldowns" => {
+                    db_update.abilities_cooldowns =
+                        abilities_cooldowns_table::parse_table_update(table_update)?
+                }
+                "accounts" => {
+                    db_update.accounts = accounts_table::parse_table_update(table_update)?
+                }
+                "admins" => db_update.admins = admins_table::parse_table_update(table_update)?,
+                "characters" => {
+                    db_update.characters = characters_table::parse_table_update(table_update)?
+                }
+                "chat_cleanups" => {
+                    db_update.chat_cleanups = chat_cleanups_table::parse_table_update(table_update)?
+                }
+                "chat_messages" => {
+                    db_update.chat_messages = chat_messages_table::parse_table_update(table_update)?
+                }
+                "consistency_checks" => {
+                    db_update.consistency_checks =
+                        consistency_checks_table::parse_table_update(table_update)?
+                }
+                "equipped_items" => {
+                    db_update.equipped_items =
+                        equipped_items_table::parse_table_update(table_update)?
+                }
+                "inventory_slots" => {
+                    db_update.inventory_slots =
+                        inventory_slots_table::parse_table_update(table_update)?
+                }
+                "item_definitions" => {
+                    db_update.item_definitions =
+                        item_definitions_table::parse_table_update(table_update)?
+                }
+                "lod_rings" => {
+                    db_update.lod_rings = lod_rings_table::parse_table_update(table_update)?
+                }
+                "movement_violations" => {
+                    db_update.movement_violations =
+                        movement_violations_table::parse_table_update(table_update)?
+                }
+                "npc_types" => {
+                    db_update.npc_types = npc_types_table::parse_table_update(table_update)?
+                }
+                "npcs" => db_update.npcs = npcs_table::parse_table_update(table_update)?,
+                "npcs_ai_ticks" => {
+                    db_update.npcs_ai_ticks = npcs_ai_ticks_table::parse_table_update(table_update)?
+                }
+                "npcs_positions" => {
+                    db_update.npcs_positions =
+                        npcs_positions_table::parse_table_update(table_update)?
+                }
+                "npcs_positions_lods" => {
+                    db_update.npcs_positions_lods =
+                        npcs_positions_lods_table::parse_table_update(table_update)?
+                }
+                "npcs_respawns" => {
+                    db_update.npcs_respawns = npcs_respawns_table::parse_table_update(table_update)?
+                }
                 "players" => db_update.players = players_table::parse_table_update(table_update)?,
+                "players_autosaves" => {
+                    db_update.players_autosaves =
+                        players_autosaves_table::parse_table_update(table_update)?
+                }
+                "players_inputs" => {
+                    db_update.players_inputs =
+                        players_inputs_table::parse_table_update(table_update)?
+                }
+                "players_inputs_acks" => {
+                    db_update.players_inputs_acks =
+                        players_inputs_acks_table::parse_table_update(table_update)?
+                }
+                "players_lods_flushes" => {
+                    db_update.players_lods_flushes =
+                        players_lods_flushes_table::parse_table_update(table_update)?
+                }
                 "players_positions" => {
                     db_update.players_positions =
                         players_positions_table::parse_table_update(table_update)?
                 }
-                "players_positions_lr" => {
-                    db_update.players_positions_lr =
-                        players_positions_lr_table::parse_table_update(table_update)?
+                "players_positions_lods" => {
+                    db_update.players_positions_lods =
+                        players_positions_lods_table::parse_table_update(table_update)?
+                }
+                "players_regen_ticks" => {
+                    db_update.players_regen_ticks =
+                        players_regen_ticks_table::parse_table_update(table_update)?
+                }
+                "players_respawns" => {
+                    db_update.players_respawns =
+                        players_respawns_table::parse_table_update(table_update)?
+                }
+                "players_simulation_ticks" => {
+                    db_update.players_simulation_ticks =
+                        players_simulation_ticks_table::parse_table_update(table_update)?
                 }
                 "players_window_updates" => {
                     db_update.players_window_updates =
@@ -134,6 +826,30 @@ impl TryFrom<__ws::DatabaseUpdate<__ws::BsatnFormat>> for DbUpdate {
                     db_update.players_windows =
                         players_windows_table::parse_table_update(table_update)?
                 }
+                "players_windows_cells" => {
+                    db_update.players_windows_cells =
+                        players_windows_cells_table::parse_table_update(table_update)?
+                }
+                "server_config" => {
+                    db_update.server_config = server_config_table::parse_table_update(table_update)?
+                }
+                "sessions" => {
+                    db_update.sessions = sessions_table::parse_table_update(table_update)?
+                }
+                "spawn_regions" => {
+                    db_update.spawn_regions = spawn_regions_table::parse_table_update(table_update)?
+                }
+                "spawn_regions_ticks" => {
+                    db_update.spawn_regions_ticks =
+                        spawn_regions_ticks_table::parse_table_update(table_update)?
+                }
+                "world_items" => {
+                    db_update.world_items = world_items_table::parse_table_update(table_update)?
+                }
+                "world_items_despawns" => {
+                    db_update.world_items_despawns =
+                        world_items_despawns_table::parse_table_update(table_update)?
+                }
 
                 unknown => {
                     return Err(__sdk::InternalError::unknown_name(
@@ -160,16 +876,109 @@ impl __sdk::DbUpdate for DbUpdate {
     ) -> AppliedDiff<'_> {
         let mut diff = AppliedDiff::default();
 
+        diff.abilities = cache
+            .apply_diff_to_table::<Ability>("abilities", &self.abilities)
+            .with_updates_by_pk(|row| &row.id);
+        diff.abilities_cooldowns = cache
+            .apply_diff_to_table::<AbilityCooldown>(
+                "abilities_cooldowns",
+                &self.abilities_cooldowns,
+            )
+            .with_updates_by_pk(|row| &row.id);
+        diff.accounts = cache
+            .apply_diff_to_table::<Account>("accounts", &self.accounts)
+            .with_updates_by_pk(|row| &row.identity);
+        diff.admins = cache
+            .apply_diff_to_table::<Admin>("admins", &self.admins)
+            .with_updates_by_pk(|row| &row.identity);
+        diff.characters = cache
+            .apply_diff_to_table::<Character>("characters", &self.characters)
+            .with_updates_by_pk(|row| &row.id);
+        diff.chat_cleanups = cache
+            .apply_diff_to_table::<ChatCleanup>("chat_cleanups", &self.chat_cleanups)
+            .with_updates_by_pk(|row| &row.id);
+        diff.chat_messages = cache
+            .apply_diff_to_table::<ChatMessage>("chat_messages", &self.chat_messages)
+            .with_updates_by_pk(|row| &row.id);
+        diff.consistency_checks = cache
+            .apply_diff_to_table::<ConsistencyCheck>("consistency_checks", &self.consistency_checks)
+            .with_updates_by_pk(|row| &row.id);
+        diff.equipped_items = cache
+            .apply_diff_to_table::<EquippedItem>("equipped_items", &self.equipped_items)
+            .with_updates_by_pk(|row| &row.id);
+        diff.inventory_slots = cache
+            .apply_diff_to_table::<InventorySlot>("inventory_slots", &self.inventory_slots)
+            .with_updates_by_pk(|row| &row.id);
+        diff.item_definitions = cache
+            .apply_diff_to_table::<ItemDefinition>("item_definitions", &self.item_definitions)
+            .with_updates_by_pk(|row| &row.id);
+        diff.lod_rings = cache
+            .apply_diff_to_table::<LodRing>("lod_rings", &self.lod_rings)
+            .with_updates_by_pk(|row| &row.ring);
+        diff.movement_violations = cache
+            .apply_diff_to_table::<MovementViolation>(
+                "movement_violations",
+                &self.movement_violations,
+            )
+            .with_updates_by_pk(|row| &row.player_id);
+        diff.npc_types = cache
+            .apply_diff_to_table::<NpcType>("npc_types", &self.npc_types)
+            .with_updates_by_pk(|row| &row.id);
+        diff.npcs = cache
+            .apply_diff_to_table::<Npc>("npcs", &self.npcs)
+            .with_updates_by_pk(|row| &row.id);
+        diff.npcs_ai_ticks = cache
+            .apply_diff_to_table::<NpcAiTick>("npcs_ai_ticks", &self.npcs_ai_ticks)
+            .with_updates_by_pk(|row| &row.id);
+        diff.npcs_positions = cache
+            .apply_diff_to_table::<NpcPosition>("npcs_positions", &self.npcs_positions)
+            .with_updates_by_pk(|row| &row.id);
+        diff.npcs_positions_lods = cache
+            .apply_diff_to_table::<NpcPositionLod>("npcs_positions_lods", &self.npcs_positions_lods)
+            .with_updates_by_pk(|row| &row.id);
+        diff.npcs_respawns = cache
+            .apply_diff_to_table::<NpcRespawn>("npcs_respawns", &self.npcs_respawns)
+            .with_updates_by_pk(|row| &row.id);
         diff.players = cache
             .apply_diff_to_table::<Player>("players", &self.players)
             .with_updates_by_pk(|row| &row.id);
+        diff.players_autosaves = cache
+            .apply_diff_to_table::<PlayerAutosave>("players_autosaves", &self.players_autosaves)
+            .with_updates_by_pk(|row| &row.id);
+        diff.players_inputs = cache
+            .apply_diff_to_table::<PlayerInput>("players_inputs", &self.players_inputs)
+            .with_updates_by_pk(|row| &row.id);
+        diff.players_inputs_acks = cache
+            .apply_diff_to_table::<PlayerInputAck>("players_inputs_acks", &self.players_inputs_acks)
+            .with_updates_by_pk(|row| &row.player_id);
+        diff.players_lods_flushes = cache
+            .apply_diff_to_table::<PlayerPositionLodFlush>(
+                "players_lods_flushes",
+                &self.players_lods_flushes,
+            )
+            .with_updates_by_pk(|row| &row.id);
         diff.players_positions = cache
             .apply_diff_to_table::<PlayerPosition>("players_positions", &self.players_positions)
             .with_updates_by_pk(|row| &row.id);
-        diff.players_positions_lr = cache
-            .apply_diff_to_table::<PlayerPosition>(
-                "players_positions_lr",
-                &self.players_positions_lr,
+        diff.players_positions_lods = cache
+            .apply_diff_to_table::<PlayerPositionLod>(
+                "players_positions_lods",
+                &self.players_positions_lods,
+            )
+            .with_updates_by_pk(|row| &row.id);
+        diff.players_regen_ticks = cache
+            .apply_diff_to_table::<PlayerRegenTick>(
+                "players_regen_ticks",
+                &self.players_regen_ticks,
+            )
+            .with_updates_by_pk(|row| &row.id);
+        diff.players_respawns = cache
+            .apply_diff_to_table::<PlayerRespawn>("players_respawns", &self.players_respawns)
+            .with_updates_by_pk(|row| &row.id);
+        diff.players_simulation_ticks = cache
+            .apply_diff_to_table::<PlayerSimulationTick>(
+                "players_simulation_ticks",
+                &self.players_simulation_ticks,
             )
             .with_updates_by_pk(|row| &row.id);
         diff.players_window_updates = cache
@@ -181,6 +990,36 @@ impl __sdk::DbUpdate for DbUpdate {
         diff.players_windows = cache
             .apply_diff_to_table::<PlayerWindow>("players_windows", &self.players_windows)
             .with_updates_by_pk(|row| &row.id);
+        diff.players_windows_cells = cache
+            .apply_diff_to_table::<PlayerWindowCell>(
+                "players_windows_cells",
+                &self.players_windows_cells,
+            )
+            .with_updates_by_pk(|row| &row.id);
+        diff.server_config = cache
+            .apply_diff_to_table::<ServerConfig>("server_config", &self.server_config)
+            .with_updates_by_pk(|row| &row.id);
+        diff.sessions = cache
+            .apply_diff_to_table::<Session>("sessions", &self.sessions)
+            .with_updates_by_pk(|row| &row.identity);
+        diff.spawn_regions = cache
+            .apply_diff_to_table::<SpawnRegion>("spawn_regions", &self.spawn_regions)
+            .with_updates_by_pk(|row| &row.id);
+        diff.spawn_regions_ticks = cache
+            .apply_diff_to_table::<SpawnRegionTick>(
+                "spawn_regions_ticks",
+                &self.spawn_regions_ticks,
+            )
+            .with_updates_by_pk(|row| &row.id);
+        diff.world_items = cache
+            .apply_diff_to_table::<WorldItem>("world_items", &self.world_items)
+            .with_updates_by_pk(|row| &row.id);
+        diff.world_items_despawns = cache
+            .apply_diff_to_table::<WorldItemDespawn>(
+                "world_items_despawns",
+                &self.world_items_despawns,
+            )
+            .with_updates_by_pk(|row| &row.id);
 
         diff
     }
@@ -190,11 +1029,44 @@ impl __sdk::DbUpdate for DbUpdate {
 #[allow(non_snake_case)]
 #[doc(hidden)]
 pub struct AppliedDiff<'r> {
+    abilities: __sdk::TableAppliedDiff<'r, Ability>,
+    abilities_cooldowns: __sdk::TableAppliedDiff<'r, AbilityCooldown>,
+    accounts: __sdk::TableAppliedDiff<'r, Account>,
+    admins: __sdk::TableAppliedDiff<'r, Admin>,
+    characters: __sdk::TableAppliedDiff<'r, Character>,
+    chat_cleanups: __sdk::TableAppliedDiff<'r, ChatCleanup>,
+    chat_messages: __sdk::TableAppliedDiff<'r, ChatMessage>,
+    consistency_checks: __sdk::TableAppliedDiff<'r, ConsistencyCheck>,
+    equipped_items: __sdk::TableAppliedDiff<'r, EquippedItem>,
+    inventory_slots: __sdk::TableAppliedDiff<'r, InventorySlot>,
+    item_definitions: __sdk::TableAppliedDiff<'r, ItemDefinition>,
+    lod_rings: __sdk::TableAppliedDiff<'r, LodRing>,
+    movement_violations: __sdk::TableAppliedDiff<'r, MovementViolation>,
+    npc_types: __sdk::TableAppliedDiff<'r, NpcType>,
+    npcs: __sdk::TableAppliedDiff<'r, Npc>,
+    npcs_ai_ticks: __sdk::TableAppliedDiff<'r, NpcAiTick>,
+    npcs_positions: __sdk::TableAppliedDiff<'r, NpcPosition>,
+    npcs_positions_lods: __sdk::TableAppliedDiff<'r, NpcPositionLod>,
+    npcs_respawns: __sdk::TableAppliedDiff<'r, NpcRespawn>,
     players: __sdk::TableAppliedDiff<'r, Player>,
+    players_autosaves: __sdk::TableAppliedDiff<'r, PlayerAutosave>,
+    players_inputs: __sdk::TableAppliedDiff<'r, PlayerInput>,
+    players_inputs_acks: __sdk::TableAppliedDiff<'r, PlayerInputAck>,
+    players_lods_flushes: __sdk::TableAppliedDiff<'r, PlayerPositionLodFlush>,
     players_positions: __sdk::TableAppliedDiff<'r, PlayerPosition>,
-    players_positions_lr: __sdk::TableAppliedDiff<'r, PlayerPosition>,
+    players_positions_lods: __sdk::TableAppliedDiff<'r, PlayerPositionLod>,
+    players_regen_ticks: __sdk::TableAppliedDiff<'r, PlayerRegenTick>,
+    players_respawns: __sdk::TableAppliedDiff<'r, PlayerRespawn>,
+    players_simulation_ticks: __sdk::TableAppliedDiff<'r, PlayerSimulationTick>,
     players_window_updates: __sdk::TableAppliedDiff<'r, PlayerWindowUpdate>,
     players_windows: __sdk::TableAppliedDiff<'r, PlayerWindow>,
+    players_windows_cells: __sdk::TableAppliedDiff<'r, PlayerWindowCell>,
+    server_config: __sdk::TableAppliedDiff<'r, ServerConfig>,
+    sessions: __sdk::TableAppliedDiff<'r, Session>,
+    spawn_regions: __sdk::TableAppliedDiff<'r, SpawnRegion>,
+    spawn_regions_ticks: __sdk::TableAppliedDiff<'r, SpawnRegionTick>,
+    world_items: __sdk::TableAppliedDiff<'r, WorldItem>,
+    world_items_despawns: __sdk::TableAppliedDiff<'r, WorldItemDespawn>,
 }
 
 impl __sdk::InModule for AppliedDiff<'_> {
@@ -207,15 +1079,117 @@ impl<'r> __sdk::AppliedDiff<'r> for AppliedDiff<'r> {
         event: &EventContext,
         callbacks: &mut __sdk::DbCallbacks<RemoteModule>,
     ) {
+        callbacks.invoke_table_row_callbacks::<Ability>("abilities", &self.abilities, event);
+        callbacks.invoke_table_row_callbacks::<AbilityCooldown>(
+            "abilities_cooldowns",
+            &self.abilities_cooldowns,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Account>("accounts", &self.accounts, event);
+        callbacks.invoke_table_row_callbacks::<Admin>("admins", &self.admins, event);
+        callbacks.invoke_table_row_callbacks::<Character>("characters", &self.characters, event);
+        callbacks.invoke_table_row_callbacks::<ChatCleanup>(
+            "chat_cleanups",
+            &self.chat_cleanups,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<ChatMessage>(
+            "chat_messages",
+            &self.chat_messages,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<ConsistencyCheck>(
+            "consistency_checks",
+            &self.consistency_checks,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<EquippedItem>(
+            "equipped_items",
+            &self.equipped_items,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<InventorySlot>(
+            "inventory_slots",
+            &self.inventory_slots,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<ItemDefinition>(
+            "item_definitions",
+            &self.item_definitions,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<LodRing>("lod_rings", &self.lod_rings, event);
+        callbacks.invoke_table_row_callbacks::<MovementViolation>(
+            "movement_violations",
+            &self.movement_violations,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<NpcType>("npc_types", &self.npc_types, event);
+        callbacks.invoke_table_row_callbacks::<Npc>("npcs", &self.npcs, event);
+        callbacks.invoke_table_row_callbacks::<NpcAiTick>(
+            "npcs_ai_ticks",
+            &self.npcs_ai_ticks,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<NpcPosition>(
+            "npcs_positions",
+            &self.npcs_positions,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<NpcPositionLod>(
+            "npcs_positions_lods",
+            &self.npcs_positions_lods,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<NpcRespawn>(
+            "npcs_respawns",
+            &self.npcs_respawns,
+            event,
+        );
         callbacks.invoke_table_row_callbacks::<Player>("players", &self.players, event);
+        callbacks.invoke_table_row_callbacks::<PlayerAutosave>(
+            "players_autosaves",
+            &self.players_autosaves,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<PlayerInput>(
+            "players_inputs",
+            &self.players_inputs,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<PlayerInputAck>(
+            "players_inputs_acks",
+            &self.players_inputs_acks,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<PlayerPositionLodFlush>(
+            "players_lods_flushes",
+            &self.players_lods_flushes,
+            event,
+        );
         callbacks.invoke_table_row_callbacks::<PlayerPosition>(
             "players_positions",
             &self.players_positions,
             event,
         );
-        callbacks.invoke_table_row_callbacks::<PlayerPosition>(
-            "players_positions_lr",
-            &self.players_positions_lr,
+        callbacks.invoke_table_row_callbacks::<PlayerPositionLod>(
+            "players_positions_lods",
+            &self.players_positions_lods,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<PlayerRegenTick>(
+            "players_regen_ticks",
+            &self.players_regen_ticks,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<PlayerRespawn>(
+            "players_respawns",
+            &self.players_respawns,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<PlayerSimulationTick>(
+            "players_simulation_ticks",
+            &self.players_simulation_ticks,
             event,
         );
         callbacks.invoke_table_row_callbacks::<PlayerWindowUpdate>(
@@ -228,6 +1202,33 @@ impl<'r> __sdk::AppliedDiff<'r> for AppliedDiff<'r> {
             &self.players_windows,
             event,
         );
+        callbacks.invoke_table_row_callbacks::<PlayerWindowCell>(
+            "players_windows_cells",
+            &self.players_windows_cells,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<ServerConfig>(
+            "server_config",
+            &self.server_config,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Session>("sessions", &self.sessions, event);
+        callbacks.invoke_table_row_callbacks::<SpawnRegion>(
+            "spawn_regions",
+            &self.spawn_regions,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<SpawnRegionTick>(
+            "spawn_regions_ticks",
+            &self.spawn_regions_ticks,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<WorldItem>("world_items", &self.world_items, event);
+        callbacks.invoke_table_row_callbacks::<WorldItemDespawn>(
+            "world_items_despawns",
+            &self.world_items_despawns,
+            event,
+        );
     }
 }
 
@@ -803,10 +1804,43 @@ impl __sdk::SpacetimeModule for RemoteModule {
     type SubscriptionHandle = SubscriptionHandle;
 
     fn register_tables(client_cache: &mut __sdk::ClientCache<Self>) {
+        abilities_table::register_table(client_cache);
+        abilities_cooldowns_table::register_table(client_cache);
+        accounts_table::register_table(client_cache);
+        admins_table::register_table(client_cache);
+        characters_table::register_table(client_cache);
+        chat_cleanups_table::register_table(client_cache);
+        chat_messages_table::register_table(client_cache);
+        consistency_checks_table::register_table(client_cache);
+        equipped_items_table::register_table(client_cache);
+        inventory_slots_table::register_table(client_cache);
+        item_definitions_table::register_table(client_cache);
+        lod_rings_table::register_table(client_cache);
+        movement_violations_table::register_table(client_cache);
+        npc_types_table::register_table(client_cache);
+        npcs_table::register_table(client_cache);
+        npcs_ai_ticks_table::register_table(client_cache);
+        npcs_positions_table::register_table(client_cache);
+        npcs_positions_lods_table::register_table(client_cache);
+        npcs_respawns_table::register_table(client_cache);
         players_table::register_table(client_cache);
+        players_autosaves_table::register_table(client_cache);
+        players_inputs_table::register_table(client_cache);
+        players_inputs_acks_table::register_table(client_cache);
+        players_lods_flushes_table::register_table(client_cache);
         players_positions_table::register_table(client_cache);
-        players_positions_lr_table::register_table(client_cache);
+        players_positions_lods_table::register_table(client_cache);
+        players_regen_ticks_table::register_table(client_cache);
+        players_respawns_table::register_table(client_cache);
+        players_simulation_ticks_table::register_table(client_cache);
         players_window_updates_table::register_table(client_cache);
         players_windows_table::register_table(client_cache);
+        players_windows_cells_table::register_table(client_cache);
+        server_config_table::register_table(client_cache);
+        sessions_table::register_table(client_cache);
+        spawn_regions_table::register_table(client_cache);
+        spawn_regions_ticks_table::register_table(client_cache);
+        world_items_table::register_table(client_cache);
+        world_items_despawns_table::register_table(client_cache);
     }
 }
@@ -4,12 +4,17 @@
 #![allow(unused, clippy::all)]
 use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 
+use super::movement_state_type::MovementState;
+
 #[derive(__lib::ser::Serialize, __lib::de::Deserialize, Clone, PartialEq, Debug)]
 #[sats(crate = __lib)]
 pub(super) struct MovePlayerArgs {
     pub x: f32,
     pub y: f32,
     pub z: f32,
+    pub yaw: f32,
+    pub movement: MovementState,
+    pub speed: f32,
 }
 
 impl From<MovePlayerArgs> for super::Reducer {
@@ -18,6 +23,9 @@ impl From<MovePlayerArgs> for super::Reducer {
             x: args.x,
             y: args.y,
             z: args.z,
+            yaw: args.yaw,
+            movement: args.movement,
+            speed: args.speed,
         }
     }
 }
@@ -38,7 +46,15 @@ pub trait move_player {
     /// This method returns immediately, and errors only if we are unable to send the request.
     /// The reducer will run asynchronously in the future,
     ///  and its status can be observed by listening for [`Self::on_move_player`] callbacks.
-    fn move_player(&self, x: f32, y: f32, z: f32) -> __sdk::Result<()>;
+    fn move_player(
+        &self,
+        x: f32,
+        y: f32,
+        z: f32,
+        yaw: f32,
+        movement: MovementState,
+        speed: f32,
+    ) -> __sdk::Result<()>;
     /// Register a callback to run whenever we are notified of an invocation of the reducer `move_player`.
     ///
     /// Callbacks should inspect the [`__sdk::ReducerEvent`] contained in the [`super::ReducerEventContext`]
@@ -48,7 +64,9 @@ pub trait move_player {
     /// to cancel the callback.
     fn on_move_player(
         &self,
-        callback: impl FnMut(&super::ReducerEventContext, &f32, &f32, &f32) + Send + 'static,
+        callback: impl FnMut(&super::ReducerEventContext, &f32, &f32, &f32, &f32, &MovementState, &f32)
+            + Send
+            + 'static,
     ) -> MovePlayerCallbackId;
     /// Cancel a callback previously registered by [`Self::on_move_player`],
     /// causing it not to run in the future.
@@ -56,13 +74,32 @@ pub trait move_player {
 }
 
 impl move_player for super::RemoteReducers {
-    fn move_player(&self, x: f32, y: f32, z: f32) -> __sdk::Result<()> {
-        self.imp
-            .call_reducer("move_player", MovePlayerArgs { x, y, z })
+    fn move_player(
+        &self,
+        x: f32,
+        y: f32,
+        z: f32,
+        yaw: f32,
+        movement: MovementState,
+        speed: f32,
+    ) -> __sdk::Result<()> {
+        self.imp.call_reducer(
+            "move_player",
+            MovePlayerArgs {
+                x,
+                y,
+                z,
+                yaw,
+                movement,
+                speed,
+            },
+        )
     }
     fn on_move_player(
         &self,
-        mut callback: impl FnMut(&super::ReducerEventContext, &f32, &f32, &f32) + Send + 'static,
+        mut callback: impl FnMut(&super::ReducerEventContext, &f32, &f32, &f32, &f32, &MovementState, &f32)
+            + Send
+            + 'static,
     ) -> MovePlayerCallbackId {
         MovePlayerCallbackId(self.imp.on_reducer(
             "move_player",
@@ -70,7 +107,15 @@ impl move_player for super::RemoteReducers {
                 let super::ReducerEventContext {
                     event:
                         __sdk::ReducerEvent {
-                            reducer: super::Reducer::MovePlayer { x, y, z },
+                            reducer:
+                                super::Reducer::MovePlayer {
+                                    x,
+                                    y,
+                                    z,
+                                    yaw,
+                                    movement,
+                                    speed,
+                                },
                             ..
                         },
                     ..
@@ -78,7 +123,7 @@ impl move_player for super::RemoteReducers {
                 else {
                     unreachable!()
                 };
-                callback(ctx, x, y, z)
+                callback(ctx, x, y, z, yaw, movement, speed)
             }),
         ))
     }
@@ -4,14 +4,24 @@
 #![allow(unused, clippy::all)]
 use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 
+use super::movement_state_type::MovementState;
+
 #[derive(__lib::ser::Serialize, __lib::de::Deserialize, Clone, PartialEq, Debug)]
 #[sats(crate = __lib)]
 pub struct PlayerPosition {
-    pub id: __sdk::Identity,
+    pub id: u64,
     pub x: f32,
     pub y: f32,
     pub z: f32,
-    pub update_count: u8,
+    pub yaw: f32,
+    pub movement: MovementState,
+    pub speed: f32,
+    pub velocity_y: f32,
+    pub speed_multiplier: f32,
+    pub last_update: __sdk::Timestamp,
+    pub move_budget: f32,
+    pub input_budget: f32,
+    pub dirty: bool,
 }
 
 impl __sdk::InModule for PlayerPosition {
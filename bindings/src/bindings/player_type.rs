@@ -7,15 +7,20 @@ use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 #[derive(__lib::ser::Serialize, __lib::de::Deserialize, Clone, PartialEq, Debug)]
 #[sats(crate = __lib)]
 pub struct Player {
-    pub id: __sdk::Identity,
+    pub id: u64,
+    pub name: String,
     pub health: f32,
     pub max_health: f32,
     pub mana: f32,
     pub max_mana: f32,
+    pub speed_multiplier: f32,
+    pub bonus_damage: f32,
     pub x: f32,
     pub y: f32,
     pub z: f32,
     pub online: bool,
+    pub dead: bool,
+    pub last_combat: __sdk::Timestamp,
 }
 
 impl __sdk::InModule for Player {
@@ -7,17 +7,11 @@ use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 #[derive(__lib::ser::Serialize, __lib::de::Deserialize, Clone, PartialEq, Debug)]
 #[sats(crate = __lib)]
 pub struct PlayerWindow {
-    pub id: __sdk::Identity,
-    pub lr_bl_x: f32,
-    pub lr_bl_z: f32,
-    pub lr_tr_x: f32,
-    pub lr_tr_z: f32,
-    pub lr_size: f32,
-    pub hr_bl_x: f32,
-    pub hr_bl_z: f32,
-    pub hr_tr_x: f32,
-    pub hr_tr_z: f32,
-    pub hr_size: f32,
+    pub id: u64,
+    pub identity: __sdk::Identity,
+    pub center_x: f32,
+    pub center_z: f32,
+    pub view_distance: f32,
     pub recompute_distance: f32,
 }
 
@@ -2,6 +2,7 @@
 // WILL NOT BE SAVED. MODIFY TABLES IN YOUR MODULE SOURCE CODE INSTEAD.
 
 #![allow(unused, clippy::all)]
+use super::movement_state_type::MovementState;
 use super::player_position_type::PlayerPosition;
 use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 
@@ -81,7 +82,7 @@ impl<'ctx> __sdk::Table for PlayersPositionsTableHandle<'ctx> {
 #[doc(hidden)]
 pub(super) fn register_table(client_cache: &mut __sdk::ClientCache<super::RemoteModule>) {
     let _table = client_cache.get_or_make_table::<PlayerPosition>("players_positions");
-    _table.add_unique_constraint::<__sdk::Identity>("id", |row| &row.id);
+    _table.add_unique_constraint::<u64>("id", |row| &row.id);
 }
 pub struct PlayersPositionsUpdateCallbackId(__sdk::CallbackId);
 
@@ -119,7 +120,7 @@ pub(super) fn parse_table_update(
 /// but to directly chain method calls,
 /// like `ctx.db.players_positions().id().find(...)`.
 pub struct PlayersPositionsIdUnique<'ctx> {
-    imp: __sdk::UniqueConstraintHandle<PlayerPosition, __sdk::Identity>,
+    imp: __sdk::UniqueConstraintHandle<PlayerPosition, u64>,
     phantom: std::marker::PhantomData<&'ctx super::RemoteTables>,
 }
 
@@ -127,7 +128,7 @@ impl<'ctx> PlayersPositionsTableHandle<'ctx> {
     /// Get a handle on the `id` unique index on the table `players_positions`.
     pub fn id(&self) -> PlayersPositionsIdUnique<'ctx> {
         PlayersPositionsIdUnique {
-            imp: self.imp.get_unique_constraint::<__sdk::Identity>("id"),
+            imp: self.imp.get_unique_constraint::<u64>("id"),
             phantom: std::marker::PhantomData,
         }
     }
@@ -136,7 +137,7 @@ impl<'ctx> PlayersPositionsTableHandle<'ctx> {
 impl<'ctx> PlayersPositionsIdUnique<'ctx> {
     /// Find the subscribed row whose `id` column value is equal to `col_val`,
     /// if such a row is present in the client cache.
-    pub fn find(&self, col_val: &__sdk::Identity) -> Option<PlayerPosition> {
+    pub fn find(&self, col_val: &u64) -> Option<PlayerPosition> {
         self.imp.find(col_val)
     }
 }
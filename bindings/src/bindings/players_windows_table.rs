@@ -81,7 +81,7 @@ impl<'ctx> __sdk::Table for PlayersWindowsTableHandle<'ctx> {
 #[doc(hidden)]
 pub(super) fn register_table(client_cache: &mut __sdk::ClientCache<super::RemoteModule>) {
     let _table = client_cache.get_or_make_table::<PlayerWindow>("players_windows");
-    _table.add_unique_constraint::<__sdk::Identity>("id", |row| &row.id);
+    _table.add_unique_constraint::<u64>("id", |row| &row.id);
 }
 pub struct PlayersWindowsUpdateCallbackId(__sdk::CallbackId);
 
@@ -119,7 +119,7 @@ pub(super) fn parse_table_update(
 /// but to directly chain method calls,
 /// like `ctx.db.players_windows().id().find(...)`.
 pub struct PlayersWindowsIdUnique<'ctx> {
-    imp: __sdk::UniqueConstraintHandle<PlayerWindow, __sdk::Identity>,
+    imp: __sdk::UniqueConstraintHandle<PlayerWindow, u64>,
     phantom: std::marker::PhantomData<&'ctx super::RemoteTables>,
 }
 
@@ -127,7 +127,7 @@ impl<'ctx> PlayersWindowsTableHandle<'ctx> {
     /// Get a handle on the `id` unique index on the table `players_windows`.
     pub fn id(&self) -> PlayersWindowsIdUnique<'ctx> {
         PlayersWindowsIdUnique {
-            imp: self.imp.get_unique_constraint::<__sdk::Identity>("id"),
+            imp: self.imp.get_unique_constraint::<u64>("id"),
             phantom: std::marker::PhantomData,
         }
     }
@@ -136,7 +136,7 @@ impl<'ctx> PlayersWindowsTableHandle<'ctx> {
 impl<'ctx> PlayersWindowsIdUnique<'ctx> {
     /// Find the subscribed row whose `id` column value is equal to `col_val`,
     /// if such a row is present in the client cache.
-    pub fn find(&self, col_val: &__sdk::Identity) -> Option<PlayerWindow> {
+    pub fn find(&self, col_val: &u64) -> Option<PlayerWindow> {
         self.imp.find(col_val)
     }
 }
@@ -1,16 +1,25 @@
-use std::time::{Duration, Instant};
-
-use bindings::{DbConnection, move_player};
+use std::{
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::{Duration, Instant},
+};
+
+use bindings::{
+    CharacterClass, CharactersTableAccess, DbConnection, MovementState, create_character,
+    move_player, select_character,
+};
 use clap::Parser;
 use rand::random_range;
-use spacetimedb_sdk::DbContext;
+use spacetimedb_sdk::{DbContext, Identity, Status, Table};
 use tokio::time::{interval, sleep};
 
 const MODULE_NAME: &str = "ariaonline";
 const STDB_URI: &str = "https://stdb.jlavocat.eu";
 // const STDB_URI: &str = "https://maincloud.spacetimedb.com";
 const MOVE_SPEED: f32 = 0.4;
-const BOUNDS: f32 = 128.0; // Movement bounds for the bots
+const BOUNDS: f32 = 64.0; // Movement bounds for the bots, the server clamps everyone to WORLD_HALF_SIZE
 
 #[derive(Parser, Debug, Clone, Copy)]
 struct Args {
@@ -19,6 +28,51 @@ struct Args {
     #[clap(short, default_value = "false")]
     /// If true, bots will not subscribe to the database
     no_subscribe: bool,
+    #[clap(short, long, default_value = "5")]
+    /// Interval in seconds between two move_player round-trip reports
+    report_interval: u64,
+}
+
+// Round-trip time of move_player calls shared by all bots, used to compare reducer cost
+#[derive(Default)]
+struct MoveStats {
+    calls: AtomicU64,
+    failures: AtomicU64,
+    total_micros: AtomicU64,
+    max_micros: AtomicU64,
+}
+
+impl MoveStats {
+    fn record(&self, elapsed: Duration, committed: bool) {
+        let micros = elapsed.as_micros() as u64;
+        self.calls.fetch_add(1, Ordering::Relaxed);
+        self.total_micros.fetch_add(micros, Ordering::Relaxed);
+        self.max_micros.fetch_max(micros, Ordering::Relaxed);
+        if !committed {
+            self.failures.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    fn report(&self, num_bots: usize, interval: Duration) {
+        let calls = self.calls.swap(0, Ordering::Relaxed);
+        let failures = self.failures.swap(0, Ordering::Relaxed);
+        let total_micros = self.total_micros.swap(0, Ordering::Relaxed);
+        let max_micros = self.max_micros.swap(0, Ordering::Relaxed);
+
+        if calls == 0 {
+            println!("[stats] {} bots, no move_player call completed", num_bots);
+            return;
+        }
+
+        println!(
+            "[stats] {} bots, {:.0} move_player/s, avg {:.2}ms, max {:.2}ms, {} failed",
+            num_bots,
+            calls as f64 / interval.as_secs_f64(),
+            total_micros as f64 / calls as f64 / 1000.0,
+            max_micros as f64 / 1000.0,
+            failures
+        );
+    }
 }
 
 #[tokio::main]
@@ -28,10 +82,23 @@ async fn main() {
 
     println!("Running with arguments: {:?}", args);
 
+    let stats = Arc::new(MoveStats::default());
+    let report_interval = Duration::from_secs(args.report_interval.max(1));
+
+    let report_stats = stats.clone();
+    tokio::spawn(async move {
+        let mut report = interval(report_interval);
+        report.tick().await;
+        loop {
+            report.tick().await;
+            report_stats.report(num_bots, report_interval);
+        }
+    });
+
     let mut handles = vec![];
 
     for i in 0..num_bots {
-        let handle = tokio::spawn(run_bot(i + 1, args));
+        let handle = tokio::spawn(run_bot(i + 1, args, stats.clone()));
         handles.push(handle);
     }
 
@@ -40,32 +107,63 @@ async fn main() {
     }
 }
 
-async fn run_bot(id: usize, args: Args) {
+// Character names can only contain letters, spell the identity with them
+fn bot_name(identity: &Identity) -> String {
+    let suffix: String = identity
+        .to_string()
+        .chars()
+        .take(13)
+        .map(|c| (b'a' + c.to_digit(16).unwrap() as u8) as char)
+        .collect();
+    format!("Bot{}", suffix)
+}
+
+async fn run_bot(id: usize, args: Args, stats: Arc<MoveStats>) {
     let delay = id * 50;
     sleep(Duration::from_millis(delay as u64)).await;
 
     let conn = DbConnection::builder()
         .with_module_name(MODULE_NAME)
-        .on_connect(move |ctx, _id, _c| {
+        .on_connect(move |ctx, identity, _c| {
             println!("[{}] Connected to SpacetimeDB as {}", id, ctx.identity());
-            if args.no_subscribe {
-                return;
-            }
 
-            ctx.subscription_builder()
-                .on_error(move |_, err| {
-                    panic!("[{}] Subscription error: {}", id, err);
-                })
-                .subscribe([
+            // Enter the world as soon as the bot's character exists
+            ctx.db.characters().on_insert(|ctx, character| {
+                ctx.reducers.select_character(character.id).unwrap();
+            });
+
+            let subscription = ctx.subscription_builder().on_error(move |_, err| {
+                panic!("[{}] Subscription error: {}", id, err);
+            });
+            if args.no_subscribe {
+                subscription.subscribe(["SELECT * FROM characters"]);
+            } else {
+                subscription.subscribe([
+                    "SELECT * FROM characters",
                     "SELECT * FROM players",
-                    "SELECT * FROM players_positions",
-                    "SELECT * FROM players_positions_lr",
+                    "SELECT * FROM players_positions_lods",
                 ]);
+            }
+
+            ctx.reducers
+                .create_character(bot_name(&identity), CharacterClass::Warrior)
+                .unwrap();
         })
         .with_uri(STDB_URI)
         .build()
         .expect("Failed to create SpacetimeDB connection");
 
+    let last_move_sent: Arc<Mutex<Option<Instant>>> = Arc::default();
+    let pending_move = last_move_sent.clone();
+    conn.reducers.on_move_player(move |ctx, _, _, _, _, _, _| {
+        if let Some(sent_at) = pending_move.lock().unwrap().take() {
+            stats.record(
+                sent_at.elapsed(),
+                matches!(ctx.event.status, Status::Committed),
+            );
+        }
+    });
+
     let mut current_position: (f32, f32) = (0.0, 0.0);
     let mut goal = (random_range(-BOUNDS..BOUNDS), random_range(-BOUNDS..BOUNDS));
     let mut last_goal_update = Instant::now();
@@ -98,8 +196,19 @@ async fn run_bot(id: usize, args: Args) {
                     let pos_x = current_position.0 + dir_x * MOVE_SPEED;
                     let pos_z = current_position.1 + dir_z * MOVE_SPEED;
 
+                    // Characters face -Z when their yaw is zero
+                    let yaw = (-dir_x).atan2(-dir_z);
+
                     current_position = (pos_x, pos_z);
-                    if let Err(e) = conn.reducers.move_player(pos_x, 1.0, pos_z) {
+                    *last_move_sent.lock().unwrap() = Some(Instant::now());
+                    if let Err(e) = conn.reducers.move_player(
+                        pos_x,
+                        1.0,
+                        pos_z,
+                        yaw,
+                        MovementState::Walking,
+                        MOVE_SPEED / move_interval.period().as_secs_f32(),
+                    ) {
                         eprintln!("[{}] Error moving player: {:?}", id, e);
                         return;
                     }
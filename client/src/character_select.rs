@@ -0,0 +1,371 @@
+use std::sync::{Arc, Mutex};
+
+use bevy::prelude::*;
+use bevy_inspector_egui::bevy_egui::{EguiContextPass, EguiContexts, egui};
+use bevy_spacetimedb::StdbConnection;
+use bindings::{
+    Character, CharacterClass, CharactersTableAccess, DbConnection, SubscriptionHandle,
+    create_character, delete_character, select_character,
+};
+use spacetimedb_sdk::{DbContext, Status, SubscriptionHandle as _, Table};
+
+use crate::{
+    server::{ConnectionStatus, current_character},
+    state::GameState,
+};
+
+const CLASSES: [CharacterClass; 3] = [
+    CharacterClass::Warrior,
+    CharacterClass::Mage,
+    CharacterClass::Rogue,
+];
+
+// Result of a character reducer called by the menus, sent back from the connection thread
+enum ReducerOutcome {
+    Created(String),
+    Deleted,
+    Selected,
+    Failed(String),
+}
+
+#[derive(Resource, Default)]
+struct ReducerOutcomes(Arc<Mutex<Vec<ReducerOutcome>>>);
+
+impl ReducerOutcomes {
+    fn sender(&self) -> impl Fn(ReducerOutcome) + Send + 'static {
+        let outcomes = self.0.clone();
+        move |outcome| outcomes.lock().unwrap().push(outcome)
+    }
+}
+
+// Subscription to the characters and session of the account, kept for the whole connection
+#[derive(Resource, Default)]
+struct AccountSubscription(Option<SubscriptionHandle>);
+
+#[derive(Resource)]
+struct CharacterScreens {
+    selected: Option<u64>,
+    name: String,
+    class: CharacterClass,
+    // Waiting for the server to answer the last request, the buttons are disabled meanwhile
+    pending: bool,
+    error: Option<String>,
+}
+
+impl Default for CharacterScreens {
+    fn default() -> Self {
+        Self {
+            selected: None,
+            name: String::new(),
+            class: CharacterClass::Warrior,
+            pending: false,
+            error: None,
+        }
+    }
+}
+
+pub struct CharacterSelectPlugin;
+
+impl Plugin for CharacterSelectPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ReducerOutcomes>()
+            .init_resource::<AccountSubscription>()
+            .init_resource::<CharacterScreens>()
+            .add_systems(OnEnter(GameState::Connecting), register_reducer_callbacks)
+            .add_systems(
+                Update,
+                (
+                    subscribe_to_account.run_if(in_state(GameState::Connecting)),
+                    handle_reducer_outcomes,
+                    enter_world.run_if(in_state(GameState::EnteringWorld)),
+                ),
+            )
+            .add_systems(
+                EguiContextPass,
+                (
+                    show_connecting.run_if(in_state(GameState::Connecting)),
+                    show_character_select.run_if(in_state(GameState::CharacterSelect)),
+                    show_character_create.run_if(in_state(GameState::CharacterCreate)),
+                    show_entering_world.run_if(in_state(GameState::EnteringWorld)),
+                    show_connection_failed.run_if(in_state(GameState::ConnectionFailed)),
+                ),
+            );
+    }
+}
+
+fn register_reducer_callbacks(
+    conn: Res<StdbConnection<DbConnection>>,
+    outcomes: Res<ReducerOutcomes>,
+) {
+    let send = outcomes.sender();
+    conn.reducers().on_create_character(move |ctx, name, _| {
+        if ctx.event.caller_identity != ctx.identity() {
+            return;
+        }
+        send(match &ctx.event.status {
+            Status::Committed => ReducerOutcome::Created(name.clone()),
+            Status::Failed(err) => ReducerOutcome::Failed(err.to_string()),
+            Status::OutOfEnergy => ReducerOutcome::Failed("Server out of energy".to_string()),
+        });
+    });
+
+    let send = outcomes.sender();
+    conn.reducers().on_delete_character(move |ctx, _| {
+        if ctx.event.caller_identity != ctx.identity() {
+            return;
+        }
+        send(match &ctx.event.status {
+            Status::Committed => ReducerOutcome::Deleted,
+            Status::Failed(err) => ReducerOutcome::Failed(err.to_string()),
+            Status::OutOfEnergy => ReducerOutcome::Failed("Server out of energy".to_string()),
+        });
+    });
+
+    let send = outcomes.sender();
+    conn.reducers().on_select_character(move |ctx, _| {
+        if ctx.event.caller_identity != ctx.identity() {
+            return;
+        }
+        send(match &ctx.event.status {
+            Status::Committed => ReducerOutcome::Selected,
+            Status::Failed(err) => ReducerOutcome::Failed(err.to_string()),
+            Status::OutOfEnergy => ReducerOutcome::Failed("Server out of energy".to_string()),
+        });
+    });
+}
+
+// Once connected, subscribe to the characters of the account and show them when they arrive
+fn subscribe_to_account(
+    conn: Res<StdbConnection<DbConnection>>,
+    status: Res<ConnectionStatus>,
+    mut subscription: ResMut<AccountSubscription>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if !status.connected {
+        return;
+    }
+
+    match &subscription.0 {
+        None => {
+            info!("Subscribing to account tables");
+            let handle = conn
+                .subscribe()
+                .on_applied(|_| {
+                    info!("Subscribed to account");
+                })
+                .on_error(|_, err| {
+                    error!("Error while subscribing to account: {}", err);
+                })
+                .subscribe(["SELECT * FROM characters", "SELECT * FROM sessions"]);
+            subscription.0 = Some(handle);
+        }
+        Some(handle) if handle.is_active() => {
+            next_state.set(GameState::CharacterSelect);
+        }
+        Some(_) => {}
+    }
+}
+
+fn handle_reducer_outcomes(
+    conn: Res<StdbConnection<DbConnection>>,
+    outcomes: Res<ReducerOutcomes>,
+    mut screens: ResMut<CharacterScreens>,
+    state: Res<State<GameState>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let outcomes = std::mem::take(&mut *outcomes.0.lock().unwrap());
+    for outcome in outcomes {
+        screens.pending = false;
+        match outcome {
+            ReducerOutcome::Created(name) => {
+                // Names are capitalized by the server
+                screens.selected = conn
+                    .db()
+                    .characters()
+                    .iter()
+                    .find(|character| character.name.eq_ignore_ascii_case(&name))
+                    .map(|character| character.id);
+                screens.name.clear();
+                screens.error = None;
+                next_state.set(GameState::CharacterSelect);
+            }
+            ReducerOutcome::Deleted => {
+                screens.selected = None;
+                screens.error = None;
+            }
+            ReducerOutcome::Selected => {}
+            ReducerOutcome::Failed(err) => {
+                warn!("Character request failed: {}", err);
+                screens.error = Some(err);
+                if *state.get() == GameState::EnteringWorld {
+                    next_state.set(GameState::CharacterSelect);
+                }
+            }
+        }
+    }
+}
+
+// The world tables are keyed by the character of the session, wait for it before subscribing
+fn enter_world(
+    conn: Res<StdbConnection<DbConnection>>,
+    screens: Res<CharacterScreens>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if screens.selected.is_some() && current_character(&conn) == screens.selected {
+        next_state.set(GameState::InGame);
+    }
+}
+
+fn centered_window(title: &str) -> egui::Window<'_> {
+    egui::Window::new(title)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .collapsible(false)
+        .resizable(false)
+}
+
+fn show_error(ui: &mut egui::Ui, error: &Option<String>) {
+    if let Some(error) = error {
+        ui.colored_label(egui::Color32::RED, error);
+    }
+}
+
+fn show_connecting(mut contexts: EguiContexts) {
+    centered_window("Aria Online").show(contexts.ctx_mut(), |ui| {
+        ui.horizontal(|ui| {
+            ui.spinner();
+            ui.label("Connecting to the server...");
+        });
+    });
+}
+
+fn show_character_select(
+    mut contexts: EguiContexts,
+    conn: Res<StdbConnection<DbConnection>>,
+    mut screens: ResMut<CharacterScreens>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let identity = conn.identity();
+    let mut characters: Vec<Character> = conn
+        .db()
+        .characters()
+        .iter()
+        .filter(|character| character.account == identity)
+        .collect();
+    characters.sort_by_key(|character| character.created_at);
+
+    if screens
+        .selected
+        .is_some_and(|id| !characters.iter().any(|character| character.id == id))
+    {
+        screens.selected = None;
+    }
+    if screens.selected.is_none() {
+        screens.selected = characters.first().map(|character| character.id);
+    }
+
+    centered_window("Characters").show(contexts.ctx_mut(), |ui| {
+        if characters.is_empty() {
+            ui.label("No character yet, create one to start playing.");
+        }
+
+        for character in &characters {
+            let label = format!("{} - {:?}", character.name, character.class);
+            if ui
+                .selectable_label(screens.selected == Some(character.id), label)
+                .clicked()
+            {
+                screens.selected = Some(character.id);
+            }
+        }
+
+        ui.separator();
+        show_error(ui, &screens.error);
+
+        ui.add_enabled_ui(!screens.pending, |ui| {
+            ui.horizontal(|ui| {
+                if let Some(id) = screens.selected {
+                    if ui.button("Play").clicked() {
+                        conn.reducers().select_character(id).unwrap();
+                        screens.pending = true;
+                        screens.error = None;
+                        next_state.set(GameState::EnteringWorld);
+                    }
+                    if ui.button("Delete").clicked() {
+                        conn.reducers().delete_character(id).unwrap();
+                        screens.pending = true;
+                        screens.error = None;
+                    }
+                }
+                if ui.button("Create new").clicked() {
+                    screens.error = None;
+                    next_state.set(GameState::CharacterCreate);
+                }
+            });
+        });
+    });
+}
+
+fn show_character_create(
+    mut contexts: EguiContexts,
+    conn: Res<StdbConnection<DbConnection>>,
+    mut screens: ResMut<CharacterScreens>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    centered_window("New character").show(contexts.ctx_mut(), |ui| {
+        ui.horizontal(|ui| {
+            ui.label("Name");
+            ui.text_edit_singleline(&mut screens.name);
+        });
+
+        let class = screens.class;
+        egui::ComboBox::from_label("Class")
+            .selected_text(format!("{:?}", class))
+            .show_ui(ui, |ui| {
+                for class in CLASSES {
+                    ui.selectable_value(&mut screens.class, class, format!("{:?}", class));
+                }
+            });
+
+        ui.separator();
+        show_error(ui, &screens.error);
+
+        ui.add_enabled_ui(!screens.pending, |ui| {
+            ui.horizontal(|ui| {
+                if ui.button("Create").clicked() {
+                    let name = screens.name.trim().to_string();
+                    conn.reducers()
+                        .create_character(name, screens.class)
+                        .unwrap();
+                    screens.pending = true;
+                    screens.error = None;
+                }
+                if ui.button("Back").clicked() {
+                    screens.error = None;
+                    next_state.set(GameState::CharacterSelect);
+                }
+            });
+        });
+    });
+}
+
+fn show_entering_world(mut contexts: EguiContexts) {
+    centered_window("Aria Online").show(contexts.ctx_mut(), |ui| {
+        ui.horizontal(|ui| {
+            ui.spinner();
+            ui.label("Entering the world...");
+        });
+    });
+}
+
+fn show_connection_failed(
+    mut contexts: EguiContexts,
+    status: Res<ConnectionStatus>,
+    mut exit: EventWriter<AppExit>,
+) {
+    centered_window("Connection lost").show(contexts.ctx_mut(), |ui| {
+        show_error(ui, &status.error);
+        if ui.button("Quit").clicked() {
+            exit.write(AppExit::Success);
+        }
+    });
+}
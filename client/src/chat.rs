@@ -0,0 +1,160 @@
+use std::sync::{Arc, Mutex};
+
+use bevy::prelude::*;
+use bevy_inspector_egui::bevy_egui::{EguiContextPass, EguiContexts, egui};
+use bevy_spacetimedb::StdbConnection;
+use bindings::{ChatChannel, ChatMessage, ChatMessagesTableAccess, DbConnection, send_message};
+use leafwing_input_manager::prelude::ActionState;
+use spacetimedb_sdk::{DbContext, Identity, Status, Table};
+
+use crate::{
+    input::Actions,
+    local_player::LocalPlayer,
+    state::{GameState, InGameSet},
+};
+
+// Messages shown in the chat box, older ones stay in the table until the server cleans them up
+const VISIBLE_MESSAGES: usize = 100;
+
+#[derive(Resource, Default)]
+struct ChatInput {
+    text: String,
+}
+
+// Errors kept under the messages, the oldest are dropped first
+const VISIBLE_ERRORS: usize = 3;
+
+// Messages refused by the server, sent back from the connection thread
+#[derive(Resource, Default)]
+struct ChatErrors(Arc<Mutex<Vec<String>>>);
+
+pub struct ChatPlugin;
+
+impl Plugin for ChatPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ChatInput>()
+            .init_resource::<ChatErrors>()
+            .add_systems(OnEnter(GameState::InGame), register_send_callback)
+            .add_systems(Update, block_game_input_while_typing.in_set(InGameSet))
+            .add_systems(
+                EguiContextPass,
+                show_chat.run_if(in_state(GameState::InGame)),
+            );
+    }
+}
+
+fn register_send_callback(conn: Res<StdbConnection<DbConnection>>, errors: Res<ChatErrors>) {
+    let errors = errors.0.clone();
+    conn.reducers().on_send_message(move |ctx, _, _, _| {
+        if ctx.event.caller_identity != ctx.identity() {
+            return;
+        }
+        if let Status::Failed(err) = &ctx.event.status {
+            let mut errors = errors.lock().unwrap();
+            errors.push(err.to_string());
+            if errors.len() > VISIBLE_ERRORS {
+                errors.remove(0);
+            }
+        }
+    });
+}
+
+// Keys typed in the chat box must not move the character nor cast abilities
+fn block_game_input_while_typing(
+    mut contexts: EguiContexts,
+    actions: Single<&mut ActionState<Actions>, With<LocalPlayer>>,
+) {
+    let mut actions = actions.into_inner();
+    let typing = contexts.ctx_mut().wants_keyboard_input();
+    if typing && !actions.disabled_all() {
+        actions.disable_all();
+    } else if !typing && actions.disabled_all() {
+        actions.enable_all();
+    }
+}
+
+// "/g text" speaks to everyone and "/w Name text" whispers, anything else is said locally
+fn parse_message(input: &str) -> Option<(ChatChannel, Option<String>, String)> {
+    let input = input.trim();
+    if let Some(text) = input.strip_prefix("/g ") {
+        return Some((ChatChannel::Global, None, text.to_string()));
+    }
+    if let Some(rest) = input.strip_prefix("/w ") {
+        let (recipient, text) = rest.trim_start().split_once(' ')?;
+        return Some((
+            ChatChannel::Whisper,
+            Some(recipient.to_string()),
+            text.to_string(),
+        ));
+    }
+    if input.is_empty() {
+        return None;
+    }
+    Some((ChatChannel::Local, None, input.to_string()))
+}
+
+fn format_message(message: &ChatMessage, local_identity: Identity) -> (egui::Color32, String) {
+    match message.channel {
+        ChatChannel::Local => (
+            egui::Color32::WHITE,
+            format!("{}: {}", message.sender_name, message.text),
+        ),
+        ChatChannel::Global => (
+            egui::Color32::from_rgb(255, 200, 120),
+            format!("[Global] {}: {}", message.sender_name, message.text),
+        ),
+        ChatChannel::Whisper if message.sender == local_identity => (
+            egui::Color32::from_rgb(230, 130, 255),
+            format!("To {}: {}", message.recipient_name, message.text),
+        ),
+        ChatChannel::Whisper => (
+            egui::Color32::from_rgb(230, 130, 255),
+            format!("From {}: {}", message.sender_name, message.text),
+        ),
+    }
+}
+
+fn show_chat(
+    mut contexts: EguiContexts,
+    mut input: ResMut<ChatInput>,
+    errors: Res<ChatErrors>,
+    conn: Res<StdbConnection<DbConnection>>,
+) {
+    let mut messages: Vec<ChatMessage> = conn.db().chat_messages().iter().collect();
+    messages.sort_by_key(|message| message.sent_at);
+    let skipped = messages.len().saturating_sub(VISIBLE_MESSAGES);
+    let local_identity = conn.identity();
+
+    egui::Window::new("Chat")
+        .anchor(egui::Align2::LEFT_BOTTOM, [10.0, -10.0])
+        .default_width(400.0)
+        .resizable(false)
+        .show(contexts.ctx_mut(), |ui| {
+            egui::ScrollArea::vertical()
+                .max_height(200.0)
+                .stick_to_bottom(true)
+                .show(ui, |ui| {
+                    for message in &messages[skipped..] {
+                        let (color, text) = format_message(message, local_identity);
+                        ui.colored_label(color, text);
+                    }
+                    for error in errors.0.lock().unwrap().iter() {
+                        ui.colored_label(egui::Color32::RED, error);
+                    }
+                });
+
+            let response = ui.add(
+                egui::TextEdit::singleline(&mut input.text)
+                    .hint_text("/g global, /w Name whisper")
+                    .desired_width(f32::INFINITY),
+            );
+            if response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                if let Some((channel, recipient, text)) = parse_message(&input.text) {
+                    conn.reducers()
+                        .send_message(channel, recipient, text)
+                        .unwrap();
+                }
+                input.text.clear();
+            }
+        });
+}
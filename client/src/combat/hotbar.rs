@@ -0,0 +1,145 @@
+use bevy::prelude::*;
+use bevy_inspector_egui::bevy_egui::{EguiContexts, egui};
+use bevy_spacetimedb::StdbConnection;
+use bindings::{
+    AbilitiesCooldownsTableAccess, AbilitiesTableAccess, Ability, DbConnection, cast_ability,
+    cast_ability_on_npc,
+};
+use leafwing_input_manager::prelude::ActionState;
+use spacetimedb_sdk::Table;
+
+use super::targeting::CurrentTarget;
+use crate::{
+    input::Actions,
+    local_player::LocalPlayer,
+    remote_npcs::RemoteNpc,
+    remote_players::{RemotePlayer, ServerClock},
+    server::current_character,
+};
+
+const HOTBAR_ACTIONS: [Actions; 5] = [
+    Actions::Ability1,
+    Actions::Ability2,
+    Actions::Ability3,
+    Actions::Ability4,
+    Actions::Ability5,
+];
+
+// Abilities in the order of the hotbar slots
+fn hotbar_abilities(conn: &StdbConnection<DbConnection>) -> Vec<Ability> {
+    let mut abilities: Vec<Ability> = conn.db().abilities().iter().collect();
+    abilities.sort_by_key(|ability| ability.id);
+    abilities.truncate(HOTBAR_ACTIONS.len());
+    abilities
+}
+
+// Seconds left before the ability can be cast again, estimated with the server clock
+fn cooldown_remaining(
+    conn: &StdbConnection<DbConnection>,
+    ability: &Ability,
+    clock: &ServerClock,
+    time: &Time<Real>,
+) -> f32 {
+    let (Some(character), Some(now)) =
+        (current_character(conn), clock.now(time.elapsed_secs_f64()))
+    else {
+        return 0.0;
+    };
+
+    conn.db()
+        .abilities_cooldowns()
+        .iter()
+        .find(|cooldown| cooldown.player_id == character && cooldown.ability_id == ability.id)
+        .map_or(0.0, |cooldown| {
+            let ready_at = cooldown.ready_at.to_micros_since_unix_epoch() as f64 / 1_000_000.0;
+            (ready_at - now).max(0.0) as f32
+        })
+}
+
+// Players and NPCs are hit through different reducers
+enum Target {
+    Player(u64),
+    Npc(u64),
+}
+
+type TargetQuery<'w, 's> =
+    Query<'w, 's, (Option<&'static RemotePlayer>, Option<&'static RemoteNpc>)>;
+
+fn target_of(target: &CurrentTarget, remotes: &TargetQuery) -> Option<Target> {
+    match target.entity.and_then(|entity| remotes.get(entity).ok())? {
+        (Some(player), _) => Some(Target::Player(player.id)),
+        (_, Some(npc)) => Some(Target::Npc(npc.id)),
+        _ => None,
+    }
+}
+
+// The server checks range, mana and cooldowns again, this only avoids pointless requests
+fn cast(
+    conn: &StdbConnection<DbConnection>,
+    ability: &Ability,
+    target: Option<Target>,
+    remaining: f32,
+) {
+    let Some(target) = target else {
+        info!("{} needs a target", ability.name);
+        return;
+    };
+    if remaining > 0.0 {
+        return;
+    }
+
+    match target {
+        Target::Player(id) => conn.reducers().cast_ability(id, ability.id).unwrap(),
+        Target::Npc(id) => conn.reducers().cast_ability_on_npc(id, ability.id).unwrap(),
+    }
+}
+
+pub fn use_hotbar(
+    actions: Single<&ActionState<Actions>, With<LocalPlayer>>,
+    target: Res<CurrentTarget>,
+    remotes: TargetQuery,
+    clock: Res<ServerClock>,
+    time: Res<Time<Real>>,
+    conn: Res<StdbConnection<DbConnection>>,
+) {
+    let abilities = hotbar_abilities(&conn);
+    for (action, ability) in HOTBAR_ACTIONS.iter().zip(&abilities) {
+        if actions.just_pressed(action) {
+            let remaining = cooldown_remaining(&conn, ability, &clock, &time);
+            cast(&conn, ability, target_of(&target, &remotes), remaining);
+        }
+    }
+}
+
+pub fn show_hotbar(
+    mut contexts: EguiContexts,
+    target: Res<CurrentTarget>,
+    remotes: TargetQuery,
+    clock: Res<ServerClock>,
+    time: Res<Time<Real>>,
+    conn: Res<StdbConnection<DbConnection>>,
+) {
+    let abilities = hotbar_abilities(&conn);
+
+    egui::Window::new("Hotbar")
+        .anchor(egui::Align2::CENTER_BOTTOM, [0.0, -10.0])
+        .title_bar(false)
+        .resizable(false)
+        .show(contexts.ctx_mut(), |ui| {
+            ui.horizontal(|ui| {
+                for (slot, ability) in abilities.iter().enumerate() {
+                    let remaining = cooldown_remaining(&conn, ability, &clock, &time);
+                    let label = if remaining > 0.0 {
+                        format!("{} {}\n{:.1}s", slot + 1, ability.name, remaining)
+                    } else {
+                        format!("{} {}", slot + 1, ability.name)
+                    };
+
+                    let button = egui::Button::new(label).min_size(egui::vec2(90.0, 40.0));
+                    if ui.add_enabled(remaining <= 0.0, button).clicked() {
+                        cast(&conn, ability, target_of(&target, &remotes), remaining);
+                    }
+                }
+            });
+        });
+}
@@ -0,0 +1,67 @@
+use bevy::prelude::*;
+use bevy_inspector_egui::bevy_egui::EguiContextPass;
+use bevy_spacetimedb::StdbConnection;
+use bindings::{DbConnection, cast_ability, cast_ability_on_npc};
+use hotbar::{show_hotbar, use_hotbar};
+use spacetimedb_sdk::{DbContext, Status};
+use targeting::{
+    CurrentTarget, clear_lost_target, cycle_target, select_target_under_cursor, show_target_frame,
+    show_target_marker,
+};
+
+use crate::state::{GameState, InGameSet};
+
+mod hotbar;
+mod targeting;
+
+pub struct CombatPlugin;
+
+impl Plugin for CombatPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CurrentTarget>()
+            .add_systems(OnEnter(GameState::InGame), register_cast_callback)
+            .add_systems(
+                Update,
+                (
+                    clear_lost_target,
+                    select_target_under_cursor,
+                    cycle_target,
+                    use_hotbar,
+                    show_target_marker,
+                )
+                    .chain()
+                    .in_set(InGameSet),
+            )
+            .add_systems(
+                EguiContextPass,
+                (show_target_frame, show_hotbar).run_if(in_state(GameState::InGame)),
+            );
+    }
+}
+
+// Casts are validated by the server, report the ones it refused
+fn register_cast_callback(conn: Res<StdbConnection<DbConnection>>) {
+    conn.reducers().on_cast_ability(|ctx, target, ability_id| {
+        if ctx.event.caller_identity != ctx.identity() {
+            return;
+        }
+        if let Status::Failed(err) = &ctx.event.status {
+            warn!(
+                "Could not cast ability {} on player {}: {}",
+                ability_id, target, err
+            );
+        }
+    });
+    conn.reducers()
+        .on_cast_ability_on_npc(|ctx, npc_id, ability_id| {
+            if ctx.event.caller_identity != ctx.identity() {
+                return;
+            }
+            if let Status::Failed(err) = &ctx.event.status {
+                warn!(
+                    "Could not cast ability {} on NPC {}: {}",
+                    ability_id, npc_id, err
+                );
+            }
+        });
+}
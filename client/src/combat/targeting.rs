@@ -0,0 +1,166 @@
+use std::f32::consts::FRAC_PI_2;
+
+use avian3d::prelude::{SpatialQuery, SpatialQueryFilter};
+use bevy::{color::palettes::css::RED, prelude::*, window::PrimaryWindow};
+use bevy_health_bar3d::prelude::Percentage;
+use bevy_inspector_egui::bevy_egui::{EguiContexts, egui};
+use bevy_spacetimedb::StdbConnection;
+use bindings::{DbConnection, NpcsTableAccess, PlayersTableAccess};
+use leafwing_input_manager::prelude::ActionState;
+
+use crate::{
+    input::Actions,
+    local_player::{LocalPlayer, LocalPlayerCamera},
+    remote_npcs::RemoteNpc,
+    remote_players::{Health, Mana, RemotePlayer},
+};
+
+// Longest distance at which a player can be picked with the mouse
+const TARGET_RAY_LENGTH: f32 = 100.0;
+// Tab only cycles through the players closer than this
+const TAB_TARGET_RANGE: f32 = 40.0;
+
+// Entities that can be targeted, remote players and NPCs
+pub type Targetable = Or<(With<RemotePlayer>, With<RemoteNpc>)>;
+
+#[derive(Resource, Default)]
+pub struct CurrentTarget {
+    pub entity: Option<Entity>,
+}
+
+// The target left the interest window or despawned
+pub fn clear_lost_target(mut target: ResMut<CurrentTarget>, remotes: Query<(), Targetable>) {
+    if target
+        .entity
+        .is_some_and(|entity| !remotes.contains(entity))
+    {
+        target.entity = None;
+    }
+}
+
+// Target the remote player or NPC under the cursor, clicking anything else clears the target
+pub fn select_target_under_cursor(
+    mut contexts: EguiContexts,
+    player: Single<(Entity, &ActionState<Actions>), With<LocalPlayer>>,
+    camera: Single<(&Camera, &GlobalTransform), With<LocalPlayerCamera>>,
+    window: Single<&Window, With<PrimaryWindow>>,
+    spatial_query: SpatialQuery,
+    remotes: Query<(), Targetable>,
+    mut target: ResMut<CurrentTarget>,
+) {
+    let (local_player, actions) = player.into_inner();
+    if !actions.just_pressed(&Actions::SelectTarget) || contexts.ctx_mut().is_pointer_over_area() {
+        return;
+    }
+
+    // While the camera holds the cursor it stays hidden at the center of the window
+    let cursor = window.cursor_position().unwrap_or(window.size() / 2.0);
+    let (camera, camera_transform) = camera.into_inner();
+    let Ok(ray) = camera.viewport_to_world(camera_transform, cursor) else {
+        return;
+    };
+
+    let filter = SpatialQueryFilter::default().with_excluded_entities([local_player]);
+    let hit = spatial_query.cast_ray(ray.origin, ray.direction, TARGET_RAY_LENGTH, true, &filter);
+    target.entity = hit
+        .map(|hit| hit.entity)
+        .filter(|entity| remotes.contains(*entity));
+}
+
+// Move to the next closest remote player or NPC
+pub fn cycle_target(
+    player: Single<(&Transform, &ActionState<Actions>), With<LocalPlayer>>,
+    remotes: Query<(Entity, &Transform), Targetable>,
+    mut target: ResMut<CurrentTarget>,
+) {
+    let (transform, actions) = player.into_inner();
+    if !actions.just_pressed(&Actions::CycleTarget) {
+        return;
+    }
+
+    let mut candidates: Vec<(Entity, f32)> = remotes
+        .iter()
+        .map(|(entity, remote)| (entity, remote.translation.distance(transform.translation)))
+        .filter(|(_, distance)| *distance <= TAB_TARGET_RANGE)
+        .collect();
+    candidates.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+    let current = target
+        .entity
+        .and_then(|current| candidates.iter().position(|(entity, _)| *entity == current));
+    let next = match current {
+        Some(index) => candidates.get(index + 1).or(candidates.first()),
+        None => candidates.first(),
+    };
+    target.entity = next.map(|(entity, _)| *entity);
+}
+
+pub fn show_target_marker(
+    mut gizmos: Gizmos,
+    target: Res<CurrentTarget>,
+    remotes: Query<&GlobalTransform, Targetable>,
+) {
+    let Some(transform) = target.entity.and_then(|entity| remotes.get(entity).ok()) else {
+        return;
+    };
+
+    // Circle at the feet of the target
+    let position = transform.translation() - Vec3::Y * 0.45;
+    gizmos.circle(
+        Isometry3d::new(position, Quat::from_rotation_x(FRAC_PI_2)),
+        0.6,
+        RED,
+    );
+}
+
+pub fn show_target_frame(
+    mut contexts: EguiContexts,
+    target: Res<CurrentTarget>,
+    remotes: Query<(
+        Option<&RemotePlayer>,
+        Option<&RemoteNpc>,
+        &Health,
+        Option<&Mana>,
+    )>,
+    conn: Res<StdbConnection<DbConnection>>,
+) {
+    let Some((remote_player, remote_npc, health, mana)) =
+        target.entity.and_then(|entity| remotes.get(entity).ok())
+    else {
+        return;
+    };
+    let player = remote_player.and_then(|remote| conn.db().players().id().find(&remote.id));
+    let npc = remote_npc.and_then(|remote| conn.db().npcs().id().find(&remote.id));
+    let name = match (&player, &npc) {
+        (Some(player), _) => player.name.as_str(),
+        (_, Some(npc)) => npc.name.as_str(),
+        _ => "",
+    };
+
+    egui::Window::new("Target")
+        .anchor(egui::Align2::CENTER_TOP, [0.0, 10.0])
+        .title_bar(false)
+        .resizable(false)
+        .show(contexts.ctx_mut(), |ui| {
+            ui.set_width(200.0);
+            ui.horizontal(|ui| {
+                ui.strong(name);
+                if player.as_ref().is_some_and(|player| player.dead) {
+                    ui.colored_label(egui::Color32::RED, "Dead");
+                }
+            });
+            ui.add(
+                egui::ProgressBar::new(health.value())
+                    .text(format!("{:.0} / {:.0}", health.current, health.max))
+                    .fill(egui::Color32::DARK_RED),
+            );
+            // NPCs have no mana
+            if let Some(mana) = mana {
+                ui.add(
+                    egui::ProgressBar::new(mana.value())
+                        .text(format!("{:.0} / {:.0}", mana.current, mana.max))
+                        .fill(egui::Color32::DARK_BLUE),
+                );
+            }
+        });
+}
@@ -1,14 +1,20 @@
 use bevy::prelude::*;
 use physics_gizmos::PhysicsGizmosPlugin;
 use player_window::PlayerWindowDebugPlugin;
+use prediction::PredictionDebugPlugin;
 
 mod physics_gizmos;
 mod player_window;
+mod prediction;
 
 pub struct DebugPlugin;
 
 impl Plugin for DebugPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins((PlayerWindowDebugPlugin, PhysicsGizmosPlugin));
+        app.add_plugins((
+            PlayerWindowDebugPlugin,
+            PhysicsGizmosPlugin,
+            PredictionDebugPlugin,
+        ));
     }
 }
@@ -1,14 +1,19 @@
 use bevy::{
-    color::palettes::css::{RED, YELLOW},
+    color::palettes::css::{ORANGE, RED, YELLOW},
     prelude::*,
 };
 use bevy_spacetimedb::StdbConnection;
-use bindings::{DbConnection, PlayersWindowsTableAccess, SubscriptionHandle};
+use bindings::{
+    DbConnection, LodRingsTableAccess, PlayersWindowsTableAccess, ServerConfigTableAccess,
+    SubscriptionHandle,
+};
 use leafwing_input_manager::prelude::ActionState;
 use spacetimedb_sdk::{SubscriptionHandle as _, Table};
 
 use crate::{input::Actions, local_player::LocalPlayer};
 
+const RING_COLORS: [Srgba; 3] = [RED, ORANGE, YELLOW];
+
 #[derive(Resource, Default)]
 pub struct EnablePlayerWindowGizmos {
     pub enabled: bool,
@@ -37,11 +42,15 @@ fn toggle_player_window_gizmos(
     info!("Toggled player window gizmos: {}", debug_gizmos.enabled);
 
     if debug_gizmos.enabled {
-        let subscription = format!(
-            "SELECT * FROM players_windows WHERE id = 0x{}",
-            conn.identity()
-        );
-        let err_subscription = subscription.clone();
+        let subscription = [
+            format!(
+                "SELECT * FROM players_windows WHERE identity = 0x{}",
+                conn.identity()
+            ),
+            "SELECT * FROM lod_rings".to_string(),
+            "SELECT * FROM server_config".to_string(),
+        ];
+        let err_subscription = subscription.join(", ");
 
         let subscription = conn
             .subscribe()
@@ -70,17 +79,21 @@ fn show_player_window_gizmos(
         return;
     }
 
-    for window in conn.db().players_windows().iter() {
-        let center_x = window.hr_bl_x + (window.hr_tr_x - window.hr_bl_x) / 2.0;
-        let center_z = window.hr_bl_z + (window.hr_tr_z - window.hr_bl_z) / 2.0;
+    let Some(config) = conn.db().server_config().iter().next() else {
+        return;
+    };
 
+    for window in conn.db().players_windows().iter() {
         let isometry = Isometry3d::new(
-            Vec3::new(center_x, player.translation.y, center_z),
+            Vec3::new(window.center_x, player.translation.y, window.center_z),
             Quat::from_rotation_x(90.0f32.to_radians()),
         );
 
         // gizmos.sphere(isometry, 5.0, RED);
-        gizmos.rect(isometry, Vec2::splat(window.hr_size), RED);
-        gizmos.rect(isometry, Vec2::splat(window.lr_size), YELLOW);
+        for ring in conn.db().lod_rings().iter() {
+            let size = ring.size * window.view_distance / config.default_view_distance;
+            let color = RING_COLORS[(ring.ring as usize).min(RING_COLORS.len() - 1)];
+            gizmos.rect(isometry, Vec2::splat(size), color);
+        }
     }
 }
@@ -0,0 +1,81 @@
+use bevy::{
+    color::palettes::css::{LIME, RED},
+    prelude::*,
+};
+use bevy_inspector_egui::bevy_egui::{EguiContextPass, EguiContexts, egui};
+use leafwing_input_manager::prelude::ActionState;
+
+use crate::{
+    input::Actions,
+    local_player::{LocalPlayer, Prediction},
+};
+
+#[derive(Resource, Default)]
+pub struct EnablePredictionOverlay {
+    pub enabled: bool,
+}
+
+pub struct PredictionDebugPlugin;
+
+impl Plugin for PredictionDebugPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EnablePredictionOverlay>()
+            .add_systems(Update, toggle_prediction_overlay)
+            .add_systems(Update, show_prediction_gizmos)
+            .add_systems(EguiContextPass, show_prediction_stats);
+    }
+}
+
+fn toggle_prediction_overlay(
+    mut overlay: ResMut<EnablePredictionOverlay>,
+    actions: Single<&ActionState<Actions>>,
+) {
+    if !actions.just_pressed(&Actions::DebugTogglePredictionOverlay) {
+        return;
+    }
+
+    overlay.enabled = !overlay.enabled;
+    info!("Toggled prediction overlay: {}", overlay.enabled);
+}
+
+// Draw the last position acknowledged by the server next to the predicted body
+fn show_prediction_gizmos(
+    mut gizmos: Gizmos,
+    overlay: Res<EnablePredictionOverlay>,
+    player: Single<(&Transform, &Prediction), With<LocalPlayer>>,
+) {
+    if !overlay.enabled {
+        return;
+    }
+
+    let (transform, prediction) = player.into_inner();
+    let Some(server_position) = prediction.server_position else {
+        return;
+    };
+
+    gizmos.sphere(Isometry3d::from_translation(server_position), 0.3, RED);
+    gizmos.line(transform.translation, server_position, LIME);
+}
+
+fn show_prediction_stats(
+    mut contexts: EguiContexts,
+    overlay: Res<EnablePredictionOverlay>,
+    prediction: Single<&Prediction, With<LocalPlayer>>,
+) {
+    if !overlay.enabled {
+        return;
+    }
+
+    egui::Window::new("Prediction").show(contexts.ctx_mut(), |ui| {
+        let error = prediction.last_error;
+        ui.label(format!(
+            "Last error: {:.3}m ({:.2}, {:.2}, {:.2})",
+            error.length(),
+            error.x,
+            error.y,
+            error.z
+        ));
+        ui.label(format!("Max error: {:.3}m", prediction.max_error));
+        ui.label(format!("Snaps: {}", prediction.snaps));
+    });
+}
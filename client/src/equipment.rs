@@ -0,0 +1,199 @@
+use bevy::prelude::*;
+use bevy_spacetimedb::{ReadDeleteEvent, ReadInsertEvent, StdbConnection};
+use bindings::{DbConnection, EquipmentSlot, EquippedItem, EquippedItemsTableAccess};
+use spacetimedb_sdk::Table;
+
+use crate::{
+    local_player::LocalPlayer,
+    remote_players::{RemotePlayer, RemotePlayersRegistry},
+    server::current_character,
+    state::InGameSet,
+};
+
+// Placeholder meshes standing for the gear until the character model has proper ones
+#[derive(Resource)]
+struct GearAssets {
+    head: Handle<Mesh>,
+    chest: Handle<Mesh>,
+    legs: Handle<Mesh>,
+    feet: Handle<Mesh>,
+    weapon: Handle<Mesh>,
+    cloth: Handle<StandardMaterial>,
+    metal: Handle<StandardMaterial>,
+}
+
+impl GearAssets {
+    fn mesh(&self, slot: EquipmentSlot) -> (Handle<Mesh>, Handle<StandardMaterial>, Transform) {
+        match slot {
+            EquipmentSlot::Head => (
+                self.head.clone(),
+                self.cloth.clone(),
+                Transform::from_xyz(0.0, 1.15, 0.0),
+            ),
+            EquipmentSlot::Chest => (
+                self.chest.clone(),
+                self.cloth.clone(),
+                Transform::from_xyz(0.0, 0.6, 0.0),
+            ),
+            EquipmentSlot::Legs => (
+                self.legs.clone(),
+                self.cloth.clone(),
+                Transform::from_xyz(0.0, 0.1, 0.0),
+            ),
+            EquipmentSlot::Feet => (
+                self.feet.clone(),
+                self.cloth.clone(),
+                Transform::from_xyz(0.0, -0.43, 0.03),
+            ),
+            EquipmentSlot::Weapon => (
+                self.weapon.clone(),
+                self.metal.clone(),
+                Transform::from_xyz(0.35, 0.4, -0.3),
+            ),
+        }
+    }
+}
+
+// Mesh of a worn item, child of the player wearing it
+#[derive(Component)]
+pub struct GearMesh {
+    pub slot: EquipmentSlot,
+}
+
+pub struct EquipmentPlugin;
+
+impl Plugin for EquipmentPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, create_gear_assets).add_systems(
+            Update,
+            (
+                attach_gear_to_new_players,
+                on_item_equipped,
+                on_item_unequipped,
+            )
+                .chain()
+                .in_set(InGameSet),
+        );
+    }
+}
+
+fn create_gear_assets(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.insert_resource(GearAssets {
+        head: meshes.add(Cuboid::new(0.3, 0.2, 0.3)),
+        chest: meshes.add(Cuboid::new(0.45, 0.5, 0.3)),
+        legs: meshes.add(Cuboid::new(0.4, 0.45, 0.25)),
+        feet: meshes.add(Cuboid::new(0.4, 0.12, 0.32)),
+        weapon: meshes.add(Cuboid::new(0.05, 0.05, 0.9)),
+        cloth: materials.add(Color::srgb(0.45, 0.3, 0.18)),
+        metal: materials.add(StandardMaterial {
+            base_color: Color::srgb(0.7, 0.7, 0.75),
+            metallic: 0.9,
+            perceptual_roughness: 0.3,
+            ..default()
+        }),
+    });
+}
+
+fn spawn_gear(commands: &mut Commands, assets: &GearAssets, entity: Entity, slot: EquipmentSlot) {
+    let (mesh, material, transform) = assets.mesh(slot);
+    commands.entity(entity).with_child((
+        GearMesh { slot },
+        Name::new(format!("Gear {:?}", slot)),
+        Mesh3d(mesh),
+        MeshMaterial3d(material),
+        transform,
+    ));
+}
+
+fn equipped_by(conn: &StdbConnection<DbConnection>, owner: u64) -> Vec<EquippedItem> {
+    conn.db()
+        .equipped_items()
+        .iter()
+        .filter(|worn| worn.owner == owner)
+        .collect()
+}
+
+// Players are spawned from their position, their gear may have arrived long before
+fn attach_gear_to_new_players(
+    mut commands: Commands,
+    assets: Res<GearAssets>,
+    local_player: Query<Entity, Added<LocalPlayer>>,
+    remote_players: Query<(Entity, &RemotePlayer), Added<RemotePlayer>>,
+    conn: Res<StdbConnection<DbConnection>>,
+) {
+    let local = local_player
+        .iter()
+        .filter_map(|entity| current_character(&conn).map(|id| (entity, id)));
+    let remote = remote_players
+        .iter()
+        .map(|(entity, remote)| (entity, remote.id));
+
+    for (entity, owner) in local.chain(remote) {
+        for worn in equipped_by(&conn, owner) {
+            spawn_gear(&mut commands, &assets, entity, worn.slot);
+        }
+    }
+}
+
+// Entity of the player wearing the gear, if it is spawned
+fn wearer(
+    owner: u64,
+    local_player: &Option<Single<Entity, With<LocalPlayer>>>,
+    registry: &RemotePlayersRegistry,
+    conn: &StdbConnection<DbConnection>,
+) -> Option<Entity> {
+    if Some(owner) == current_character(conn) {
+        return local_player.as_ref().map(|entity| **entity);
+    }
+    registry.get_entity(&owner)
+}
+
+fn on_item_equipped(
+    mut commands: Commands,
+    mut events: ReadInsertEvent<EquippedItem>,
+    assets: Res<GearAssets>,
+    local_player: Option<Single<Entity, With<LocalPlayer>>>,
+    registry: Res<RemotePlayersRegistry>,
+    new_players: Query<(), Or<(Added<LocalPlayer>, Added<RemotePlayer>)>>,
+    conn: Res<StdbConnection<DbConnection>>,
+) {
+    for event in events.read() {
+        let Some(entity) = wearer(event.row.owner, &local_player, &registry, &conn) else {
+            continue;
+        };
+        // Already dressed from the cache by attach_gear_to_new_players
+        if new_players.contains(entity) {
+            continue;
+        }
+        spawn_gear(&mut commands, &assets, entity, event.row.slot);
+    }
+}
+
+fn on_item_unequipped(
+    mut commands: Commands,
+    mut events: ReadDeleteEvent<EquippedItem>,
+    local_player: Option<Single<Entity, With<LocalPlayer>>>,
+    registry: Res<RemotePlayersRegistry>,
+    children: Query<&Children>,
+    gear: Query<&GearMesh>,
+    conn: Res<StdbConnection<DbConnection>>,
+) {
+    for event in events.read() {
+        let Some(entity) = wearer(event.row.owner, &local_player, &registry, &conn) else {
+            continue;
+        };
+
+        for child in children.iter_descendants(entity) {
+            if gear
+                .get(child)
+                .is_ok_and(|mesh| mesh.slot == event.row.slot)
+            {
+                commands.entity(child).despawn();
+            }
+        }
+    }
+}
@@ -11,23 +11,43 @@ pub enum Actions {
     Jump,
     Run,
     Look,
+    SelectTarget,
+    CycleTarget,
+    Ability1,
+    Ability2,
+    Ability3,
+    Ability4,
+    Ability5,
+    ToggleInventory,
+    PickUp,
 
     // Debug actions
     DebugTogglePlayerWindowGizmos,
     DebugTogglePhysicsGizmos,
+    DebugTogglePredictionOverlay,
 }
 
 pub fn create_input_map() -> InputMap<Actions> {
     let mut input_map = InputMap::<Actions>::new([
         (Actions::Jump, KeyCode::Space),
         (Actions::Run, KeyCode::ShiftLeft),
+        (Actions::CycleTarget, KeyCode::Tab),
+        (Actions::Ability1, KeyCode::Digit1),
+        (Actions::Ability2, KeyCode::Digit2),
+        (Actions::Ability3, KeyCode::Digit3),
+        (Actions::Ability4, KeyCode::Digit4),
+        (Actions::Ability5, KeyCode::Digit5),
+        (Actions::ToggleInventory, KeyCode::KeyI),
+        (Actions::PickUp, KeyCode::KeyE),
         (Actions::DebugTogglePlayerWindowGizmos, KeyCode::F12),
         (Actions::DebugTogglePhysicsGizmos, KeyCode::F11),
+        (Actions::DebugTogglePredictionOverlay, KeyCode::F10),
     ]);
 
     input_map.insert_dual_axis(Actions::Move, VirtualDPad::wasd());
 
     input_map.insert(Actions::Look, MouseButton::Right);
+    input_map.insert(Actions::SelectTarget, MouseButton::Left);
 
     input_map
 }
@@ -0,0 +1,219 @@
+use bevy::{platform::collections::HashMap, prelude::*};
+use bevy_inspector_egui::bevy_egui::{EguiContextPass, EguiContexts, egui};
+use bevy_spacetimedb::{ReadDeleteEvent, ReadInsertEvent, ReadUpdateEvent, StdbConnection};
+use bindings::{
+    DbConnection, EquipmentSlot, EquippedItem, EquippedItemsTableAccess, InventorySlot,
+    ItemDefinitionsTableAccess, drop_item, equip_item, move_item, split_stack, unequip_item,
+};
+use leafwing_input_manager::prelude::ActionState;
+use spacetimedb_sdk::Table;
+
+use crate::{
+    input::Actions,
+    local_player::LocalPlayer,
+    server::current_character,
+    state::{GameState, InGameSet},
+};
+
+// Shared with the server in server/src/inventory.rs
+const INVENTORY_SIZE: u32 = 24;
+const INVENTORY_COLUMNS: u32 = 6;
+const EQUIPMENT_SLOTS: [EquipmentSlot; 5] = [
+    EquipmentSlot::Head,
+    EquipmentSlot::Chest,
+    EquipmentSlot::Legs,
+    EquipmentSlot::Feet,
+    EquipmentSlot::Weapon,
+];
+
+// Stacks of the local player's inventory by row id, kept up to date from the table events
+#[derive(Resource, Default)]
+pub struct Inventory {
+    stacks: HashMap<u64, InventorySlot>,
+}
+
+impl Inventory {
+    pub fn in_slot(&self, slot: u32) -> Option<&InventorySlot> {
+        self.stacks.values().find(|stack| stack.slot == slot)
+    }
+}
+
+#[derive(Resource, Default)]
+struct InventoryWindow {
+    open: bool,
+    // Slot picked up by the first click, the next click moves it
+    selected: Option<u32>,
+}
+
+pub struct InventoryPlugin;
+
+impl Plugin for InventoryPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Inventory>()
+            .init_resource::<InventoryWindow>()
+            .add_systems(
+                PreUpdate,
+                (on_stack_inserted, on_stack_updated, on_stack_deleted)
+                    .chain()
+                    .in_set(InGameSet),
+            )
+            .add_systems(Update, toggle_inventory.in_set(InGameSet))
+            .add_systems(
+                EguiContextPass,
+                show_inventory.run_if(in_state(GameState::InGame)),
+            );
+    }
+}
+
+fn on_stack_inserted(mut inventory: ResMut<Inventory>, mut events: ReadInsertEvent<InventorySlot>) {
+    for event in events.read() {
+        inventory.stacks.insert(event.row.id, event.row.clone());
+    }
+}
+
+fn on_stack_updated(mut inventory: ResMut<Inventory>, mut events: ReadUpdateEvent<InventorySlot>) {
+    for event in events.read() {
+        inventory.stacks.insert(event.new.id, event.new.clone());
+    }
+}
+
+fn on_stack_deleted(mut inventory: ResMut<Inventory>, mut events: ReadDeleteEvent<InventorySlot>) {
+    for event in events.read() {
+        inventory.stacks.remove(&event.row.id);
+    }
+}
+
+fn toggle_inventory(
+    mut window: ResMut<InventoryWindow>,
+    actions: Single<&ActionState<Actions>, With<LocalPlayer>>,
+) {
+    if actions.just_pressed(&Actions::ToggleInventory) {
+        window.open = !window.open;
+        window.selected = None;
+    }
+}
+
+fn show_equipment(ui: &mut egui::Ui, conn: &StdbConnection<DbConnection>) {
+    let character = current_character(conn);
+    let worn: Vec<EquippedItem> = conn
+        .db()
+        .equipped_items()
+        .iter()
+        .filter(|worn| Some(worn.owner) == character)
+        .collect();
+
+    egui::Grid::new("equipment_grid").show(ui, |ui| {
+        for slot in EQUIPMENT_SLOTS {
+            ui.label(format!("{:?}", slot));
+            match worn.iter().find(|worn| worn.slot == slot) {
+                Some(worn) => {
+                    let name = conn
+                        .db()
+                        .item_definitions()
+                        .id()
+                        .find(&worn.item_id)
+                        .map_or("?".to_string(), |item| item.name);
+                    ui.label(name);
+                    if ui.button("Unequip").clicked() {
+                        conn.reducers().unequip_item(slot).unwrap();
+                    }
+                }
+                None => {
+                    ui.label("-");
+                }
+            }
+            ui.end_row();
+        }
+    });
+}
+
+// Click a stack then a slot to move it there, right click splits a stack in half
+fn show_inventory(
+    mut contexts: EguiContexts,
+    mut window: ResMut<InventoryWindow>,
+    inventory: Res<Inventory>,
+    conn: Res<StdbConnection<DbConnection>>,
+) {
+    if !window.open {
+        return;
+    }
+
+    let mut open = window.open;
+    egui::Window::new("Inventory")
+        .open(&mut open)
+        .resizable(false)
+        .show(contexts.ctx_mut(), |ui| {
+            egui::Grid::new("inventory_grid")
+                .spacing([4.0, 4.0])
+                .show(ui, |ui| {
+                    for slot in 0..INVENTORY_SIZE {
+                        let stack = inventory.in_slot(slot);
+                        let label = stack.map_or(String::new(), |stack| {
+                            let name = conn
+                                .db()
+                                .item_definitions()
+                                .id()
+                                .find(&stack.item_id)
+                                .map_or("?".to_string(), |item| item.name);
+                            format!("{}\n{}", name, stack.quantity)
+                        });
+
+                        let button = egui::Button::new(label)
+                            .min_size(egui::vec2(80.0, 48.0))
+                            .selected(window.selected == Some(slot));
+                        let response = ui.add(button);
+
+                        if response.clicked() {
+                            match window.selected {
+                                Some(from) => {
+                                    conn.reducers().move_item(from, slot).unwrap();
+                                    window.selected = None;
+                                }
+                                None if stack.is_some() => window.selected = Some(slot),
+                                None => {}
+                            }
+                        }
+                        if response.secondary_clicked() {
+                            if let Some(stack) = stack.filter(|stack| stack.quantity > 1) {
+                                if let Some(empty) =
+                                    (0..INVENTORY_SIZE).find(|s| inventory.in_slot(*s).is_none())
+                                {
+                                    conn.reducers()
+                                        .split_stack(slot, empty, stack.quantity / 2)
+                                        .unwrap();
+                                }
+                            }
+                        }
+
+                        if (slot + 1) % INVENTORY_COLUMNS == 0 {
+                            ui.end_row();
+                        }
+                    }
+                });
+
+            ui.separator();
+            let selected = window
+                .selected
+                .and_then(|slot| inventory.in_slot(slot).map(|stack| (slot, stack.quantity)));
+            ui.add_enabled_ui(selected.is_some(), |ui| {
+                ui.horizontal(|ui| {
+                    if ui.button("Equip").clicked() {
+                        if let Some((slot, _)) = selected {
+                            conn.reducers().equip_item(slot).unwrap();
+                            window.selected = None;
+                        }
+                    }
+                    if ui.button("Drop").clicked() {
+                        if let Some((slot, quantity)) = selected {
+                            conn.reducers().drop_item(slot, quantity).unwrap();
+                            window.selected = None;
+                        }
+                    }
+                });
+            });
+
+            ui.separator();
+            show_equipment(ui, &conn);
+        });
+    window.open = open;
+}
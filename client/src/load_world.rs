@@ -26,7 +26,7 @@ impl Plugin for LoadWorldPlugin {
     fn build(&self, app: &mut App) {
         app.add_loading_state(
             LoadingState::new(GameState::LoadingWorld)
-                .continue_to_state(GameState::InGame)
+                .continue_to_state(GameState::Connecting)
                 .load_collection::<NameplateAssets>()
                 .load_collection::<CharacterAssets>(),
         );
@@ -4,15 +4,20 @@ use bevy_tnua::{
     builtins::TnuaBuiltinJumpState,
     prelude::{TnuaBuiltinJump, TnuaBuiltinWalk, TnuaController},
 };
+use bindings::MovementState;
 
-use super::LocalPlayer;
+use super::{LocalPlayer, MovementStats};
 use crate::{
-    animation_link::AnimationEntityLink, load_world::CharacterAssets,
-    local_player::PLAYER_WALK_SPEED, state::InGameSet,
+    animation_link::AnimationEntityLink,
+    load_world::CharacterAssets,
+    local_player::PLAYER_WALK_SPEED,
+    remote_players::{RemotePlayer, RemotePlayerMovement},
+    state::InGameSet,
 };
 
 #[derive(Resource)]
 struct PlayerAnimationNodes {
+    graph: Handle<AnimationGraph>,
     standing: AnimationNodeIndex,
     walking: AnimationNodeIndex,
     running: AnimationNodeIndex,
@@ -26,13 +31,49 @@ pub enum PlayerAnimationState {
     Falling,
 }
 
+impl PlayerAnimationState {
+    // Animation matching a movement state, the playback speed follows the movement speed
+    pub fn from_movement(movement: MovementState, speed: f32) -> Self {
+        match movement {
+            MovementState::Standing => PlayerAnimationState::Standing,
+            MovementState::Walking => PlayerAnimationState::Walking(0.3 * speed),
+            MovementState::Running => PlayerAnimationState::Running(0.20 * speed),
+            MovementState::Jumping => PlayerAnimationState::Jumping,
+            MovementState::Falling => PlayerAnimationState::Falling,
+        }
+    }
+}
+
+// Movement state of the local player, replicated to the server for the other clients to animate it
+#[derive(Component)]
+pub struct LocalMovement {
+    pub movement: MovementState,
+    pub speed: f32,
+}
+
+impl Default for LocalMovement {
+    fn default() -> Self {
+        Self {
+            movement: MovementState::Standing,
+            speed: 0.0,
+        }
+    }
+}
+
 pub struct PlayerAnimationsPlugin;
 
 impl Plugin for PlayerAnimationsPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(
             FixedUpdate,
-            (prepare_animations, handle_animating).in_set(InGameSet),
+            (
+                prepare_animations,
+                attach_animation_graphs,
+                handle_animating,
+                animate_remote_players,
+            )
+                .chain()
+                .in_set(InGameSet),
         );
     }
 }
@@ -41,9 +82,13 @@ fn prepare_animations(
     mut commands: Commands,
     mut animation_graph: ResMut<Assets<AnimationGraph>>,
     character_assets: Res<CharacterAssets>,
-    player_animation_entity: Single<&AnimationEntityLink, With<LocalPlayer>>,
+    animation_nodes: Option<Res<PlayerAnimationNodes>>,
     gtfs_assets: Res<Assets<Gltf>>,
 ) {
+    if animation_nodes.is_some() {
+        return;
+    }
+
     let Some(gltf_model) = gtfs_assets.get(&character_assets.character) else {
         return;
     };
@@ -51,23 +96,46 @@ fn prepare_animations(
     let mut graph = AnimationGraph::new();
     let root_node = graph.root;
 
+    let standing = graph.add_clip(gltf_model.named_animations["Idle"].clone(), 1.0, root_node);
+    let walking = graph.add_clip(
+        gltf_model.named_animations["Walking"].clone(),
+        1.0,
+        root_node,
+    );
+    let running = graph.add_clip(
+        gltf_model.named_animations["Running"].clone(),
+        1.0,
+        root_node,
+    );
+
     commands.insert_resource(PlayerAnimationNodes {
-        standing: graph.add_clip(gltf_model.named_animations["Idle"].clone(), 1.0, root_node),
-        walking: graph.add_clip(
-            gltf_model.named_animations["Walking"].clone(),
-            1.0,
-            root_node,
-        ),
-        running: graph.add_clip(
-            gltf_model.named_animations["Running"].clone(),
-            1.0,
-            root_node,
-        ),
+        graph: animation_graph.add(graph),
+        standing,
+        walking,
+        running,
     });
+}
+
+// Local and remote characters share the same graph
+fn attach_animation_graphs(
+    mut commands: Commands,
+    animation_nodes: Option<Res<PlayerAnimationNodes>>,
+    animation_links: Query<&AnimationEntityLink>,
+    with_graph: Query<(), With<AnimationGraphHandle>>,
+) {
+    let Some(animation_nodes) = animation_nodes else {
+        return;
+    };
 
-    commands
-        .entity(player_animation_entity.0)
-        .insert(AnimationGraphHandle(animation_graph.add(graph)));
+    for animation_link in animation_links.iter() {
+        if with_graph.contains(animation_link.0) {
+            continue;
+        }
+
+        commands
+            .entity(animation_link.0)
+            .insert(AnimationGraphHandle(animation_nodes.graph.clone()));
+    }
 }
 
 fn handle_animating(
@@ -76,6 +144,8 @@ fn handle_animating(
             &TnuaController,
             &AnimationEntityLink,
             &mut TnuaAnimatingState<PlayerAnimationState>,
+            &mut LocalMovement,
+            &MovementStats,
         ),
         With<LocalPlayer>,
     >,
@@ -87,13 +157,14 @@ fn handle_animating(
         None => return,
     };
 
-    let (controller, animation_link, mut animating_state) = player.into_inner();
+    let (controller, animation_link, mut animating_state, mut local_movement, stats) =
+        player.into_inner();
 
     let mut animation_player = q_animation_players
         .get_mut(animation_link.0)
         .expect("Animation player not found");
 
-    let current_status_for_animation = match controller.action_name() {
+    let (movement, speed) = match controller.action_name() {
         Some(TnuaBuiltinJump::NAME) => {
             let (_, jump_state) = controller
                 .concrete_action::<TnuaBuiltinJump>()
@@ -101,8 +172,8 @@ fn handle_animating(
 
             match jump_state {
                 TnuaBuiltinJumpState::NoJump => return,
-                TnuaBuiltinJumpState::FallSection => PlayerAnimationState::Falling,
-                _ => PlayerAnimationState::Jumping,
+                TnuaBuiltinJumpState::FallSection => (MovementState::Falling, 0.0),
+                _ => (MovementState::Jumping, 0.0),
             }
         }
 
@@ -112,24 +183,72 @@ fn handle_animating(
                 return;
             };
             if basis_state.standing_on_entity().is_none() {
-                PlayerAnimationState::Falling
+                (MovementState::Falling, 0.0)
             } else {
                 let speed = basis_state.running_velocity.length();
                 if speed > 0.01 {
                     // Epsilon check for floating point precision
-                    if (speed - PLAYER_WALK_SPEED).abs() < 1e-4 {
-                        PlayerAnimationState::Walking(0.3 * speed)
+                    if (speed - PLAYER_WALK_SPEED * stats.speed_multiplier).abs() < 1e-4 {
+                        (MovementState::Walking, speed)
                     } else {
-                        PlayerAnimationState::Running(0.20 * speed)
+                        (MovementState::Running, speed)
                     }
                 } else {
-                    PlayerAnimationState::Standing
+                    (MovementState::Standing, 0.0)
                 }
             }
         }
     };
 
-    let animating_directive = animating_state.update_by_discriminant(current_status_for_animation);
+    local_movement.movement = movement;
+    local_movement.speed = speed;
+
+    play_animation(
+        PlayerAnimationState::from_movement(movement, speed),
+        &mut animating_state,
+        &mut animation_player,
+        &animation_nodes,
+    );
+}
+
+// Remote players play the animation of the movement state replicated by the server
+fn animate_remote_players(
+    mut players: Query<
+        (
+            &RemotePlayerMovement,
+            &AnimationEntityLink,
+            &mut TnuaAnimatingState<PlayerAnimationState>,
+        ),
+        With<RemotePlayer>,
+    >,
+    mut q_animation_players: Query<&mut AnimationPlayer>,
+    animation_nodes: Option<Res<PlayerAnimationNodes>>,
+) {
+    let Some(animation_nodes) = animation_nodes else {
+        return;
+    };
+
+    for (movement, animation_link, mut animating_state) in players.iter_mut() {
+        let Ok(mut animation_player) = q_animation_players.get_mut(animation_link.0) else {
+            continue;
+        };
+
+        play_animation(
+            PlayerAnimationState::from_movement(movement.movement, movement.speed),
+            &mut animating_state,
+            &mut animation_player,
+            &animation_nodes,
+        );
+    }
+}
+
+fn play_animation(
+    state: PlayerAnimationState,
+    animating_state: &mut TnuaAnimatingState<PlayerAnimationState>,
+    animation_player: &mut AnimationPlayer,
+    animation_nodes: &PlayerAnimationNodes,
+) {
+    let animating_directive = animating_state.update_by_discriminant(state);
 
     match animating_directive {
         TnuaAnimatingStateDirective::Maintain { state } => {
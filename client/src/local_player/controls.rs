@@ -5,14 +5,12 @@ use leafwing_input_manager::prelude::ActionState;
 use crate::input::Actions;
 
 use super::{
-    LocalPlayer, LocalPlayerCamera, PLAYER_JUMP_HEIGHT, PLAYER_RUN_SPEED, PLAYER_WALK_SPEED,
+    LocalPlayer, LocalPlayerCamera, MovementStats, PLAYER_JUMP_HEIGHT, PLAYER_RUN_SPEED,
+    PLAYER_WALK_SPEED,
 };
 
-pub fn apply_controls(
-    mut controller: Single<&mut TnuaController>,
-    actions: Single<&ActionState<Actions>, With<LocalPlayer>>,
-    camera_transform: Single<&Transform, With<LocalPlayerCamera>>,
-) {
+// World space direction of the move input, relative to the camera
+pub fn move_direction(actions: &ActionState<Actions>, camera_transform: &Transform) -> Vec3 {
     let direction = actions.clamped_axis_pair(&Actions::Move);
 
     let mut forward: Vec3 = camera_transform.forward().into();
@@ -23,13 +21,22 @@ pub fn apply_controls(
     right.y = 0.0;
     right = right.normalize();
 
-    let direction = (forward * direction.y + right * direction.x).normalize_or_zero();
+    (forward * direction.y + right * direction.x).normalize_or_zero()
+}
+
+pub fn apply_controls(
+    mut controller: Single<&mut TnuaController>,
+    player: Single<(&ActionState<Actions>, &MovementStats), With<LocalPlayer>>,
+    camera_transform: Single<&Transform, With<LocalPlayerCamera>>,
+) {
+    let (actions, stats) = player.into_inner();
+    let direction = move_direction(actions, &camera_transform);
 
     let speed = if actions.pressed(&Actions::Run) {
         PLAYER_RUN_SPEED
     } else {
         PLAYER_WALK_SPEED
-    };
+    } * stats.speed_multiplier;
 
     controller.basis(TnuaBuiltinWalk {
         desired_velocity: direction * speed,
@@ -1,22 +1,34 @@
-use animations::{PlayerAnimationState, PlayerAnimationsPlugin};
+use animations::{LocalMovement, PlayerAnimationsPlugin};
 use avian3d::prelude::*;
 use bevy::prelude::*;
-use bevy_spacetimedb::{ReadDeleteEvent, ReadInsertEvent, StdbConnection};
+use bevy_spacetimedb::{ReadDeleteEvent, ReadInsertEvent, ReadUpdateEvent, StdbConnection};
 use bevy_third_person_camera::{
     CameraSyncSet, Offset, ThirdPersonCamera, ThirdPersonCameraTarget, Zoom,
 };
 use bevy_tnua::{TnuaAnimatingState, TnuaUserControlsSystemSet, prelude::TnuaController};
 use bevy_tnua_avian3d::TnuaAvian3dSensorShape;
-use bindings::{DbConnection, Player as PlayerTable};
+use bindings::{DbConnection, MovementState, Player as PlayerTable};
 use controls::{apply_controls, rotate_character};
-use movement_replication::{MovementReplication, sync_movement_with_server};
+use movement_replication::{
+    InputReplication, MovementProtocol, MovementReplication, apply_movement_corrections,
+    submit_inputs, sync_movement_with_server,
+};
+use reconciliation::{apply_prediction_correction, reconcile_with_server, record_predicted_moves};
 
 mod animations;
 mod controls;
 mod movement_replication;
+mod reconciliation;
+
+pub use animations::PlayerAnimationState;
+pub use reconciliation::Prediction;
 
-use crate::{input::create_input_map, load_world::CharacterAssets, state::InGameSet};
+use crate::{
+    input::create_input_map, load_world::CharacterAssets, server::current_character,
+    state::InGameSet,
+};
 
+// Shared with the server simulation in server/src/movement.rs
 pub const PLAYER_WALK_SPEED: f32 = 4.0;
 pub const PLAYER_RUN_SPEED: f32 = 10.0;
 pub const PLAYER_JUMP_HEIGHT: f32 = 2.0;
@@ -27,28 +39,56 @@ pub struct LocalPlayer;
 #[derive(Component)]
 pub struct LocalPlayerCamera;
 
+// Stats of the players row the prediction depends on, must match the server's
+#[derive(Component)]
+pub struct MovementStats {
+    pub speed_multiplier: f32,
+}
+
 pub struct LocalPlayerPlugin;
 
 impl Plugin for LocalPlayerPlugin {
     fn build(&self, app: &mut App) {
         app.configure_sets(PostUpdate, CameraSyncSet.after(PhysicsSet::Sync))
             .insert_resource(UiScale(0.5))
+            .init_resource::<MovementProtocol>()
             .add_plugins(PlayerAnimationsPlugin)
             .add_systems(
                 PreUpdate,
-                (on_player_inserted, on_player_deleted)
+                (
+                    on_player_inserted,
+                    on_player_updated,
+                    on_player_deleted,
+                    apply_movement_corrections,
+                    reconcile_with_server,
+                )
                     .in_set(InGameSet)
                     .chain(),
             )
             .add_systems(
                 FixedUpdate,
-                apply_controls
-                    .in_set(TnuaUserControlsSystemSet)
+                (
+                    apply_prediction_correction.before(apply_controls),
+                    apply_controls.in_set(TnuaUserControlsSystemSet),
+                    submit_inputs
+                        .after(apply_controls)
+                        .run_if(resource_equals(MovementProtocol::Inputs)),
+                )
+                    .in_set(InGameSet),
+            )
+            .add_systems(
+                FixedPostUpdate,
+                record_predicted_moves
+                    .after(PhysicsSet::Sync)
                     .in_set(InGameSet),
             )
             .add_systems(
                 PostUpdate,
-                (rotate_character, sync_movement_with_server).in_set(InGameSet),
+                (
+                    rotate_character,
+                    sync_movement_with_server.run_if(resource_equals(MovementProtocol::Positions)),
+                )
+                    .in_set(InGameSet),
             );
     }
 }
@@ -60,7 +100,7 @@ fn on_player_inserted(
     conn: Res<StdbConnection<DbConnection>>,
 ) {
     for event in events.read() {
-        if event.row.id != conn.identity() {
+        if Some(event.row.id) != current_character(&conn) {
             continue;
         }
 
@@ -69,7 +109,7 @@ fn on_player_inserted(
         commands.spawn((
             LocalPlayer,
             Visibility::Visible,
-            Name::new(format!("Player#{}", event.row.id.to_abbreviated_hex())),
+            Name::new(format!("Player#{}", event.row.id)),
             create_input_map(),
             Transform::from_xyz(event.row.x, event.row.y, event.row.z),
             RigidBody::Dynamic,
@@ -79,11 +119,21 @@ fn on_player_inserted(
             TnuaAnimatingState::<PlayerAnimationState>::default(),
             LockedAxes::ROTATION_LOCKED,
             ThirdPersonCameraTarget,
-            MovementReplication {
-                last_position: Vec3::new(event.row.x, event.row.y, event.row.z),
-                timer: Timer::from_seconds(0.1, TimerMode::Repeating),
-                position_threshold_squarred: 0.01,
-            },
+            (
+                MovementReplication {
+                    last_position: Vec3::new(event.row.x, event.row.y, event.row.z),
+                    last_yaw: 0.0,
+                    last_movement: MovementState::Standing,
+                    timer: Timer::from_seconds(0.1, TimerMode::Repeating),
+                    position_threshold_squarred: 0.01,
+                },
+                InputReplication::default(),
+                Prediction::default(),
+                LocalMovement::default(),
+                MovementStats {
+                    speed_multiplier: event.row.speed_multiplier,
+                },
+            ),
             children![(
                 SceneRoot(character_assets.character_scene.clone()),
                 Transform::from_xyz(0.0, -0.5, 0.0)
@@ -104,6 +154,20 @@ fn on_player_inserted(
     }
 }
 
+fn on_player_updated(
+    mut events: ReadUpdateEvent<PlayerTable>,
+    mut stats: Single<&mut MovementStats, With<LocalPlayer>>,
+    conn: Res<StdbConnection<DbConnection>>,
+) {
+    for event in events.read() {
+        if Some(event.new.id) != current_character(&conn) {
+            continue;
+        }
+
+        stats.speed_multiplier = event.new.speed_multiplier;
+    }
+}
+
 fn on_player_deleted(
     mut commands: Commands,
     mut events: ReadDeleteEvent<PlayerTable>,
@@ -115,7 +179,7 @@ fn on_player_deleted(
     let camera_entity = camera_entity.into_inner();
 
     for event in events.read() {
-        if event.row.id != conn.identity() {
+        if Some(event.row.id) != current_character(&conn) {
             continue;
         }
 
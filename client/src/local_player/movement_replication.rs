@@ -1,29 +1,145 @@
+use avian3d::prelude::LinearVelocity;
 use bevy::prelude::*;
-use bevy_spacetimedb::StdbConnection;
-use bindings::{DbConnection, move_player};
+use bevy_spacetimedb::{ReadInsertEvent, ReadUpdateEvent, StdbConnection};
+use bindings::{DbConnection, MovementState, MovementViolation, move_player, submit_input};
+use leafwing_input_manager::prelude::ActionState;
+
+use crate::{input::Actions, server::current_character};
+
+use super::{LocalPlayer, LocalPlayerCamera, animations::LocalMovement, controls::move_direction};
+
+// How the local player's movement is replicated to the server
+#[derive(Resource, Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MovementProtocol {
+    // Send the position of the local simulation, validated by the server
+    Positions,
+    // Send the inputs, the server simulates the movement from them
+    #[default]
+    Inputs,
+}
+
+// Facing changes smaller than this angle, in radians, are not worth a move on their own
+const YAW_THRESHOLD: f32 = 0.05;
 
 #[derive(Component)]
 pub struct MovementReplication {
     pub timer: Timer,
     pub last_position: Vec3,
+    pub last_yaw: f32,
+    pub last_movement: MovementState,
     pub position_threshold_squarred: f32,
 }
 
+// Yaw of a character, the rotation around the vertical axis replicated to the server
+pub fn yaw(transform: &Transform) -> f32 {
+    transform.rotation.to_euler(EulerRot::YXZ).0
+}
+
 pub fn sync_movement_with_server(
     time: Res<Time>,
-    player: Single<(&GlobalTransform, &mut MovementReplication)>,
+    player: Single<(
+        &GlobalTransform,
+        &Transform,
+        &LocalMovement,
+        &mut MovementReplication,
+    )>,
     conn: Res<StdbConnection<DbConnection>>,
 ) {
-    let (player_transform, mut replication) = player.into_inner();
+    let (player_transform, transform, local_movement, mut replication) = player.into_inner();
 
     replication.timer.tick(time.delta());
     if replication.timer.just_finished() {
         let current_position = player_transform.translation();
+        let current_yaw = yaw(transform);
         let delta = current_position - replication.last_position;
-        if delta.length_squared() >= replication.position_threshold_squarred {
+        let moved = delta.length_squared() >= replication.position_threshold_squarred;
+        let turned = (current_yaw - replication.last_yaw).abs() >= YAW_THRESHOLD;
+        if moved || turned || local_movement.movement != replication.last_movement {
             let pos = player_transform.translation();
-            conn.reducers().move_player(pos.x, pos.y, pos.z).unwrap();
+            conn.reducers()
+                .move_player(
+                    pos.x,
+                    pos.y,
+                    pos.z,
+                    current_yaw,
+                    local_movement.movement,
+                    local_movement.speed,
+                )
+                .unwrap();
             replication.last_position = current_position;
+            replication.last_yaw = current_yaw;
+            replication.last_movement = local_movement.movement;
         }
     }
 }
+
+#[derive(Component, Default)]
+pub struct InputReplication {
+    // Sequence number of the last input sent, the server acknowledges inputs by sequence
+    pub last_seq: u32,
+}
+
+// Send the inputs of every fixed step, along with the step duration the server has to simulate
+pub fn submit_inputs(
+    time: Res<Time>,
+    player: Single<
+        (&ActionState<Actions>, &Transform, &mut InputReplication),
+        (With<LocalPlayer>, Without<LocalPlayerCamera>),
+    >,
+    camera_transform: Single<&Transform, With<LocalPlayerCamera>>,
+    conn: Res<StdbConnection<DbConnection>>,
+) {
+    let (actions, transform, mut replication) = player.into_inner();
+    let direction = move_direction(actions, &camera_transform);
+
+    replication.last_seq += 1;
+    conn.reducers()
+        .submit_input(
+            replication.last_seq,
+            direction.x,
+            direction.z,
+            yaw(transform),
+            actions.pressed(&Actions::Run),
+            actions.pressed(&Actions::Jump),
+            time.delta_secs(),
+        )
+        .unwrap();
+}
+
+// Snap the local player back to the position the server accepted when a move got rejected
+pub fn apply_movement_corrections(
+    mut inserted: ReadInsertEvent<MovementViolation>,
+    mut updated: ReadUpdateEvent<MovementViolation>,
+    player: Single<
+        (
+            &mut Transform,
+            &mut LinearVelocity,
+            &mut MovementReplication,
+        ),
+        With<LocalPlayer>,
+    >,
+    conn: Res<StdbConnection<DbConnection>>,
+) {
+    let character = current_character(&conn);
+    let correction = inserted
+        .read()
+        .map(|event| &event.row)
+        .chain(updated.read().map(|event| &event.new))
+        .filter(|violation| Some(violation.player_id) == character)
+        .last();
+
+    let Some(correction) = correction else {
+        return;
+    };
+
+    warn!(
+        "Movement rejected by the server ({} violations), snapping back to ({}, {}, {})",
+        correction.count, correction.x, correction.y, correction.z
+    );
+
+    let (mut transform, mut velocity, mut replication) = player.into_inner();
+    let position = Vec3::new(correction.x, correction.y, correction.z);
+    transform.translation = position;
+    velocity.0 = Vec3::ZERO;
+    replication.last_position = position;
+}
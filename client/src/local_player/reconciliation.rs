@@ -0,0 +1,143 @@
+use std::collections::VecDeque;
+
+use avian3d::prelude::LinearVelocity;
+use bevy::prelude::*;
+use bevy_spacetimedb::{ReadInsertEvent, ReadUpdateEvent, StdbConnection};
+use bindings::{DbConnection, PlayerInputAck};
+
+use super::{LocalPlayer, movement_replication::InputReplication};
+use crate::server::current_character;
+
+// Number of moves kept while waiting for the server to acknowledge them, about 2s of fixed steps
+const HISTORY_CAPACITY: usize = 128;
+// Errors below this distance are considered noise and left alone
+const ERROR_TOLERANCE: f32 = 0.05;
+// Past this distance the body is snapped to the server position instead of being smoothed
+const SNAP_THRESHOLD: f32 = 2.0;
+// Rate at which the pending correction is applied, higher is snappier
+const CORRECTION_RATE: f32 = 10.0;
+
+struct PredictedMove {
+    seq: u32,
+    position: Vec3,
+}
+
+// Ring buffer of the positions predicted after each input sent to the server
+#[derive(Component, Default)]
+pub struct Prediction {
+    moves: VecDeque<PredictedMove>,
+    // Part of the last corrections not yet applied to the body
+    correction: Vec3,
+    // Last acknowledged position and the error of the matching prediction, for the debug overlay
+    pub server_position: Option<Vec3>,
+    pub last_error: Vec3,
+    pub max_error: f32,
+    pub snaps: u32,
+}
+
+// Record where the body ended up after simulating the last input sent,
+// including the correction still to be applied so that it is not counted twice
+pub fn record_predicted_moves(
+    player: Single<(&Transform, &InputReplication, &mut Prediction), With<LocalPlayer>>,
+) {
+    let (transform, replication, mut prediction) = player.into_inner();
+    if replication.last_seq == 0
+        || prediction
+            .moves
+            .back()
+            .is_some_and(|last| last.seq == replication.last_seq)
+    {
+        return;
+    }
+
+    let position = transform.translation + prediction.correction;
+    prediction.moves.push_back(PredictedMove {
+        seq: replication.last_seq,
+        position,
+    });
+    while prediction.moves.len() > HISTORY_CAPACITY {
+        prediction.moves.pop_front();
+    }
+}
+
+// Compare the state acknowledged by the server with the prediction made for the same input
+pub fn reconcile_with_server(
+    mut inserted: ReadInsertEvent<PlayerInputAck>,
+    mut updated: ReadUpdateEvent<PlayerInputAck>,
+    player: Single<(&mut Transform, &mut LinearVelocity, &mut Prediction), With<LocalPlayer>>,
+    conn: Res<StdbConnection<DbConnection>>,
+) {
+    let character = current_character(&conn);
+    let ack = inserted
+        .read()
+        .map(|event| &event.row)
+        .chain(updated.read().map(|event| &event.new))
+        .filter(|ack| Some(ack.player_id) == character)
+        .last();
+
+    let Some(ack) = ack else {
+        return;
+    };
+
+    let (mut transform, mut velocity, mut prediction) = player.into_inner();
+    let server_position = Vec3::new(ack.x, ack.y, ack.z);
+    prediction.server_position = Some(server_position);
+
+    while prediction.moves.front().is_some_and(|m| m.seq < ack.seq) {
+        prediction.moves.pop_front();
+    }
+    let Some(predicted) = prediction.moves.pop_front() else {
+        return;
+    };
+    if predicted.seq != ack.seq {
+        // The acknowledged move already left the history
+        prediction.moves.push_front(predicted);
+        return;
+    }
+
+    let error = server_position - predicted.position;
+    prediction.last_error = error;
+    prediction.max_error = prediction.max_error.max(error.length());
+    if error.length() < ERROR_TOLERANCE {
+        return;
+    }
+
+    // The moves predicted after the acknowledged one inherit the same error
+    for predicted in prediction.moves.iter_mut() {
+        predicted.position += error;
+    }
+    prediction.correction += error;
+
+    if prediction.correction.length() > SNAP_THRESHOLD {
+        warn!(
+            "Prediction off by {:.2}m at input {}, snapping to the server position",
+            prediction.correction.length(),
+            ack.seq
+        );
+        transform.translation += prediction.correction;
+        velocity.0 = Vec3::ZERO;
+        prediction.correction = Vec3::ZERO;
+        prediction.snaps += 1;
+    }
+}
+
+// Blend the pending correction into the body over a few frames
+pub fn apply_prediction_correction(
+    time: Res<Time>,
+    player: Single<(&mut Transform, &mut Prediction), With<LocalPlayer>>,
+) {
+    let (mut transform, mut prediction) = player.into_inner();
+    if prediction.correction == Vec3::ZERO {
+        return;
+    }
+
+    let t = 1.0 - (-CORRECTION_RATE * time.delta_secs()).exp();
+    let step = if prediction.correction.length() < ERROR_TOLERANCE {
+        prediction.correction
+    } else {
+        prediction.correction * t
+    };
+
+    transform.translation += step;
+    prediction.correction -= step;
+}
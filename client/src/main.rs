@@ -10,28 +10,44 @@ use bevy_mod_billboard::plugin::BillboardPlugin;
 use bevy_third_person_camera::ThirdPersonCameraPlugin;
 use bevy_tnua::prelude::TnuaControllerPlugin;
 use bevy_tnua_avian3d::TnuaAvian3dPlugin;
+use character_select::CharacterSelectPlugin;
+use chat::ChatPlugin;
+use combat::CombatPlugin;
 use debug::DebugPlugin;
+use equipment::EquipmentPlugin;
 use input::Actions;
+use inventory::InventoryPlugin;
 use iyes_perf_ui::PerfUiPlugin;
 use iyes_perf_ui::prelude::PerfUiDefaultEntries;
 use leafwing_input_manager::plugin::InputManagerPlugin;
 use load_world::LoadWorldPlugin;
 use local_player::LocalPlayerPlugin;
+use nameplates::NameplatesPlugin;
+use remote_npcs::RemoteNpcsPlugin;
 use remote_players::RemotePlayersPlugin;
 use server::ServerPlugin;
 use state::GameStatePlugin;
 use world::WorldPlugin;
+use world_items::WorldItemsPlugin;
 
 mod animation_link;
+mod character_select;
+mod chat;
+mod combat;
 mod constants;
 mod debug;
+mod equipment;
 mod input;
+mod inventory;
 mod load_world;
 mod local_player;
+mod nameplates;
+mod remote_npcs;
 mod remote_players;
 mod server;
 mod state;
 mod world;
+mod world_items;
 
 fn main() {
     let mut app = App::new();
@@ -76,9 +92,17 @@ fn main() {
         DebugPlugin,
         ServerPlugin,
         LoadWorldPlugin,
+        CharacterSelectPlugin,
         WorldPlugin,
         LocalPlayerPlugin,
         RemotePlayersPlugin,
+        NameplatesPlugin,
+        CombatPlugin,
+        ChatPlugin,
+        InventoryPlugin,
+        EquipmentPlugin,
+        WorldItemsPlugin,
+        RemoteNpcsPlugin,
     ))
     .add_systems(Startup, startup);
 
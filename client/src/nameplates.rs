@@ -0,0 +1,65 @@
+use bevy::prelude::*;
+use bevy_mod_billboard::prelude::*;
+
+use crate::{load_world::NameplateAssets, local_player::LocalPlayerCamera, state::InGameSet};
+
+// Height of the nameplate above the feet of the player, over the health and mana bars
+const NAMEPLATE_HEIGHT: f32 = 1.9;
+const NAMEPLATE_FONT_SIZE: f32 = 60.0;
+// The text is laid out in pixels, this brings it back to world units
+const NAMEPLATE_SCALE: f32 = 0.005;
+// Nameplates start fading out past this distance from the camera and are hidden past the other
+const NAMEPLATE_FADE_DISTANCE: f32 = 25.0;
+const NAMEPLATE_CULL_DISTANCE: f32 = 40.0;
+
+#[derive(Component)]
+pub struct Nameplate;
+
+// Billboard text showing a name, to spawn as a child of the player it names
+pub fn nameplate(name: &str, assets: &NameplateAssets) -> impl Bundle {
+    (
+        Nameplate,
+        Name::new("Nameplate"),
+        BillboardText::new(name),
+        TextFont::from_font(assets.font.clone()).with_font_size(NAMEPLATE_FONT_SIZE),
+        TextColor(Color::WHITE),
+        TextLayout::new_with_justify(JustifyText::Center),
+        Transform::from_xyz(0.0, NAMEPLATE_HEIGHT, 0.0).with_scale(Vec3::splat(NAMEPLATE_SCALE)),
+    )
+}
+
+pub struct NameplatesPlugin;
+
+impl Plugin for NameplatesPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PostUpdate,
+            fade_nameplates
+                .after(TransformSystem::TransformPropagate)
+                .in_set(InGameSet),
+        );
+    }
+}
+
+// Fade the nameplates with the distance to the camera so that crowds stay readable
+fn fade_nameplates(
+    camera: Single<&GlobalTransform, With<LocalPlayerCamera>>,
+    mut nameplates: Query<(&GlobalTransform, &mut TextColor, &mut Visibility), With<Nameplate>>,
+) {
+    let camera_position = camera.translation();
+    for (transform, mut color, mut visibility) in nameplates.iter_mut() {
+        let distance = transform.translation().distance(camera_position);
+        if distance > NAMEPLATE_CULL_DISTANCE {
+            visibility.set_if_neq(Visibility::Hidden);
+            continue;
+        }
+
+        visibility.set_if_neq(Visibility::Inherited);
+        let fade = (distance - NAMEPLATE_FADE_DISTANCE)
+            / (NAMEPLATE_CULL_DISTANCE - NAMEPLATE_FADE_DISTANCE);
+        let alpha = 1.0 - fade.clamp(0.0, 1.0);
+        if (color.0.alpha() - alpha).abs() > 0.01 {
+            color.0.set_alpha(alpha);
+        }
+    }
+}
@@ -0,0 +1,245 @@
+use avian3d::prelude::{Collider, RigidBody};
+use bevy::prelude::*;
+use bevy_health_bar3d::prelude::{BarHeight, BarSettings};
+use bevy_mod_billboard::prelude::*;
+use bevy_spacetimedb::{ReadDeleteEvent, ReadInsertEvent, ReadUpdateEvent, StdbConnection};
+use bindings::{DbConnection, Npc, NpcPositionLod, NpcsTableAccess};
+
+use crate::{
+    load_world::NameplateAssets,
+    nameplates::{Nameplate, nameplate},
+    remote_players::{
+        Health, RemotePlayerMovement, ServerClock, Snapshot, SnapshotBuffer, SnapshotsRegistry,
+    },
+    state::InGameSet,
+};
+
+pub type RemoteNpcsRegistry = SnapshotsRegistry<RemoteNpc>;
+
+#[derive(Component)]
+pub struct RemoteNpc {
+    pub id: u64,
+}
+
+// Placeholder bodies until the NPCs get proper models, tinted by type
+#[derive(Resource)]
+struct NpcAssets {
+    body: Handle<Mesh>,
+    materials: Vec<Handle<StandardMaterial>>,
+}
+
+impl NpcAssets {
+    fn material(&self, type_id: u32) -> Handle<StandardMaterial> {
+        self.materials[type_id as usize % self.materials.len()].clone()
+    }
+}
+
+pub struct RemoteNpcsPlugin;
+
+impl Plugin for RemoteNpcsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RemoteNpcsRegistry>()
+            .add_systems(Startup, create_npc_assets)
+            .add_systems(
+                PreUpdate,
+                (on_npc_position_inserted, on_npc_position_deleted)
+                    .in_set(InGameSet)
+                    .chain(),
+            )
+            .add_systems(
+                Update,
+                (on_npc_position_updated, on_npc_updated).in_set(InGameSet),
+            );
+    }
+}
+
+fn create_npc_assets(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let colors = [
+        Color::srgb(0.6, 0.2, 0.6),
+        Color::srgb(0.55, 0.55, 0.6),
+        Color::srgb(0.3, 0.55, 0.2),
+        Color::srgb(0.45, 0.3, 0.2),
+    ];
+    commands.insert_resource(NpcAssets {
+        body: meshes.add(Capsule3d::new(0.35, 0.6)),
+        materials: colors.map(|color| materials.add(color)).to_vec(),
+    });
+}
+
+fn on_npc_position_inserted(
+    mut commands: Commands,
+    mut registry: ResMut<RemoteNpcsRegistry>,
+    mut events: ReadInsertEvent<NpcPositionLod>,
+    mut clock: ResMut<ServerClock>,
+    mut buffers: Query<&mut SnapshotBuffer>,
+    time: Res<Time<Real>>,
+    assets: Res<NpcAssets>,
+    nameplates: Res<NameplateAssets>,
+    conn: Res<StdbConnection<DbConnection>>,
+) {
+    for event in events.read() {
+        let row = &event.row;
+        clock.observe(snapshot_time(row), time.elapsed_secs_f64());
+
+        if registry.get_entity(&row.npc_id).is_some() {
+            registry.add_snapshot(&row.npc_id, row.id, row.ring);
+            if registry.is_finest_snapshot(&row.npc_id, row.ring) {
+                push_snapshot(&registry, &mut buffers, row);
+            }
+            continue;
+        }
+
+        // The npcs row may only arrive after the position, the stats are filled in then
+        let npc = conn.db().npcs().id().find(&row.npc_id);
+        let (health, name, type_id) = match &npc {
+            Some(npc) => (
+                Health {
+                    current: npc.health,
+                    max: npc.max_health,
+                },
+                npc.name.as_str(),
+                npc.type_id,
+            ),
+            None => (
+                Health {
+                    current: 0.0,
+                    max: 0.0,
+                },
+                "",
+                0,
+            ),
+        };
+
+        let entity = commands
+            .spawn((
+                Name::new(format!("RemoteNpc#{}", row.npc_id)),
+                Visibility::Visible,
+                RigidBody::Kinematic,
+                Collider::capsule_endpoints(
+                    0.35,
+                    Vec3::new(0.0, 0.0, 0.0),
+                    Vec3::new(0.0, 0.6, 0.0),
+                ),
+                Transform::from_xyz(row.x, row.y, row.z)
+                    .with_rotation(Quat::from_rotation_y(row.yaw)),
+                SnapshotBuffer::new(snapshot_time(row), Snapshot::from_npc_row(row)),
+                RemotePlayerMovement {
+                    movement: row.movement,
+                    speed: row.speed,
+                },
+                RemoteNpc { id: row.npc_id },
+                health,
+                BarSettings::<Health> {
+                    offset: 1.4,
+                    height: BarHeight::Static(0.05),
+                    width: 1.0,
+                    ..default()
+                },
+                children![
+                    (
+                        Mesh3d(assets.body.clone()),
+                        MeshMaterial3d(assets.material(type_id)),
+                        Transform::from_xyz(0.0, 0.15, 0.0),
+                    ),
+                    nameplate(name, &nameplates),
+                ],
+            ))
+            .id();
+
+        registry.register(row.npc_id, entity);
+        registry.add_snapshot(&row.npc_id, row.id, row.ring);
+    }
+}
+
+fn on_npc_position_deleted(
+    mut commands: Commands,
+    mut registry: ResMut<RemoteNpcsRegistry>,
+    mut events: ReadDeleteEvent<NpcPositionLod>,
+) {
+    for event in events.read() {
+        let row = &event.row;
+        let Some(entity) = registry.get_entity(&row.npc_id) else {
+            continue;
+        };
+
+        if registry.remove_snapshot(&row.npc_id, row.id) > 0 {
+            continue;
+        }
+
+        commands.entity(entity).despawn();
+        registry.remove(&row.npc_id);
+    }
+}
+
+fn on_npc_position_updated(
+    registry: Res<RemoteNpcsRegistry>,
+    mut events: ReadUpdateEvent<NpcPositionLod>,
+    mut clock: ResMut<ServerClock>,
+    mut buffers: Query<&mut SnapshotBuffer>,
+    time: Res<Time<Real>>,
+) {
+    for event in events.read() {
+        let row = &event.new;
+        clock.observe(snapshot_time(row), time.elapsed_secs_f64());
+
+        if registry.is_finest_snapshot(&row.npc_id, row.ring) {
+            push_snapshot(&registry, &mut buffers, row);
+        }
+    }
+}
+
+// Keep the health bar and nameplate of NPCs in sync with their npcs row
+fn on_npc_updated(
+    registry: Res<RemoteNpcsRegistry>,
+    mut inserted: ReadInsertEvent<Npc>,
+    mut updated: ReadUpdateEvent<Npc>,
+    mut healths: Query<&mut Health>,
+    children: Query<&Children>,
+    mut nameplates: Query<&mut BillboardText, With<Nameplate>>,
+) {
+    let rows = inserted
+        .read()
+        .map(|event| &event.row)
+        .chain(updated.read().map(|event| &event.new));
+    for row in rows {
+        let Some(entity) = registry.get_entity(&row.id) else {
+            continue;
+        };
+
+        if let Ok(mut health) = healths.get_mut(entity) {
+            health.current = row.health;
+            health.max = row.max_health;
+        }
+
+        for child in children.iter_descendants(entity) {
+            if let Ok(mut text) = nameplates.get_mut(child) {
+                if text.0 != row.name {
+                    text.0 = row.name.clone();
+                }
+            }
+        }
+    }
+}
+
+// Server time of a snapshot, in seconds
+fn snapshot_time(row: &NpcPositionLod) -> f64 {
+    row.sampled_at.to_micros_since_unix_epoch() as f64 / 1_000_000.0
+}
+
+fn push_snapshot(
+    registry: &RemoteNpcsRegistry,
+    buffers: &mut Query<&mut SnapshotBuffer>,
+    row: &NpcPositionLod,
+) {
+    let Some(entity) = registry.get_entity(&row.npc_id) else {
+        return;
+    };
+
+    if let Ok(mut buffer) = buffers.get_mut(entity) {
+        buffer.push(snapshot_time(row), Snapshot::from_npc_row(row));
+    }
+}
@@ -1,3 +1,5 @@
+use std::{collections::VecDeque, marker::PhantomData};
+
 use avian3d::prelude::{Collider, RigidBody};
 use bevy::{color::palettes::css::BLUE, platform::collections::HashMap, prelude::*};
 use bevy_health_bar3d::{
@@ -6,27 +8,206 @@ use bevy_health_bar3d::{
 };
 use bevy_mod_billboard::prelude::*;
 use bevy_spacetimedb::{ReadDeleteEvent, ReadInsertEvent, ReadUpdateEvent, StdbConnection};
-use bindings::{DbConnection, PlayerPosition, PlayersTableAccess};
-use spacetimedb_sdk::Identity;
+use bevy_tnua::TnuaAnimatingState;
+use bindings::{
+    DbConnection, MovementState, NpcPositionLod, Player, PlayerPositionLod, PlayersTableAccess,
+};
 
 use crate::{
     load_world::{CharacterAssets, NameplateAssets},
-    local_player::PLAYER_WALK_SPEED,
+    local_player::PlayerAnimationState,
+    nameplates::{Nameplate, nameplate},
+    server::current_character,
     state::InGameSet,
 };
 
+// Bounds of the delay remote players are rendered in the past with, the delay follows
+// the interval and jitter of the snapshots of each player
+const MIN_INTERPOLATION_DELAY: f64 = 0.1;
+const MAX_INTERPOLATION_DELAY: f64 = 1.5;
+// Positions are extrapolated for at most this long when snapshots stop coming
+const MAX_EXTRAPOLATION: f64 = 0.25;
+const SNAPSHOT_BUFFER_CAPACITY: usize = 32;
+// Weight of the latest sample in the interval and jitter estimates
+const ESTIMATE_SMOOTHING: f64 = 0.1;
+// Speed at which the server clock estimate forgets the least delayed snapshot, in seconds per second
+const CLOCK_DRIFT_RATE: f64 = 0.01;
+
+// Entities spawned from position snapshots, by the id of the player or NPC they show
+#[derive(Resource)]
+pub struct SnapshotsRegistry<T: Send + Sync + 'static> {
+    entities: HashMap<u64, RemoteEntry>,
+    marker: PhantomData<T>,
+}
+
+pub type RemotePlayersRegistry = SnapshotsRegistry<RemotePlayer>;
+
+impl<T: Send + Sync + 'static> Default for SnapshotsRegistry<T> {
+    fn default() -> Self {
+        Self {
+            entities: HashMap::default(),
+            marker: PhantomData,
+        }
+    }
+}
+
+// A remote entity can be visible through the snapshots of several LOD rings at once,
+// the entity lives as long as at least one of them is visible
+struct RemoteEntry {
+    entity: Entity,
+    snapshots: HashMap<u64, u8>,
+}
+
+// Estimate of the server clock, from the snapshot that took the least time to reach us
 #[derive(Resource, Default)]
-pub struct RemotePlayersRegistry {
-    entities: HashMap<Identity, Entity>,
+pub struct ServerClock {
+    offset: Option<f64>,
+    observed_at: f64,
+}
+
+impl ServerClock {
+    pub fn observe(&mut self, server_time: f64, local_time: f64) {
+        let sample = server_time - local_time;
+        let offset = match self.offset {
+            Some(offset) => {
+                let drifted = offset - (local_time - self.observed_at) * CLOCK_DRIFT_RATE;
+                drifted.max(sample)
+            }
+            None => sample,
+        };
+
+        self.offset = Some(offset);
+        self.observed_at = local_time;
+    }
+
+    pub fn now(&self, local_time: f64) -> Option<f64> {
+        self.offset.map(|offset| local_time + offset)
+    }
+}
+
+// State of a remote player at a point in time, as replicated by the server
+#[derive(Clone, Copy)]
+pub struct Snapshot {
+    pub position: Vec3,
+    pub rotation: Quat,
+    pub movement: MovementState,
+    pub speed: f32,
+}
+
+impl Snapshot {
+    pub fn from_row(row: &PlayerPositionLod) -> Self {
+        Self {
+            position: Vec3::new(row.x, row.y, row.z),
+            rotation: Quat::from_rotation_y(row.yaw),
+            movement: row.movement,
+            speed: row.speed,
+        }
+    }
+
+    pub fn from_npc_row(row: &NpcPositionLod) -> Self {
+        Self {
+            position: Vec3::new(row.x, row.y, row.z),
+            rotation: Quat::from_rotation_y(row.yaw),
+            movement: row.movement,
+            speed: row.speed,
+        }
+    }
+}
+
+struct TimedSnapshot {
+    time: f64,
+    snapshot: Snapshot,
+}
+
+// Snapshots received for a remote player, stamped with the server time they were taken at
+#[derive(Component)]
+pub struct SnapshotBuffer {
+    snapshots: VecDeque<TimedSnapshot>,
+    interval: f64,
+    jitter: f64,
+}
+
+impl SnapshotBuffer {
+    pub fn new(time: f64, snapshot: Snapshot) -> Self {
+        Self {
+            snapshots: VecDeque::from([TimedSnapshot { time, snapshot }]),
+            interval: MIN_INTERPOLATION_DELAY / 2.0,
+            jitter: 0.0,
+        }
+    }
+
+    pub fn push(&mut self, time: f64, snapshot: Snapshot) {
+        let Some(last) = self.snapshots.back() else {
+            self.snapshots.push_back(TimedSnapshot { time, snapshot });
+            return;
+        };
+        if time <= last.time {
+            return;
+        }
+
+        // Long pauses of a stopped player are not representative of the snapshot rate
+        let gap = (time - last.time).min(MAX_INTERPOLATION_DELAY);
+        self.jitter += ((gap - self.interval).abs() - self.jitter) * ESTIMATE_SMOOTHING;
+        self.interval += (gap - self.interval) * ESTIMATE_SMOOTHING;
+
+        self.snapshots.push_back(TimedSnapshot { time, snapshot });
+        while self.snapshots.len() > SNAPSHOT_BUFFER_CAPACITY {
+            self.snapshots.pop_front();
+        }
+    }
+
+    // How far in the past the player is rendered so that a snapshot is usually available on both sides
+    pub fn delay(&self) -> f64 {
+        (self.interval + self.jitter * 2.0).clamp(MIN_INTERPOLATION_DELAY, MAX_INTERPOLATION_DELAY)
+    }
+
+    // State of the player at the given server time, dropping the snapshots no longer needed
+    pub fn sample(&mut self, time: f64) -> Option<Snapshot> {
+        while self.snapshots.len() > 2 && self.snapshots[1].time <= time {
+            self.snapshots.pop_front();
+        }
+
+        let first = self.snapshots.front()?;
+        if time <= first.time {
+            return Some(first.snapshot);
+        }
+
+        let Some(next) = self.snapshots.get(1) else {
+            return Some(first.snapshot);
+        };
+        if time <= next.time {
+            let t = ((time - first.time) / (next.time - first.time)) as f32;
+            return Some(Snapshot {
+                position: first.snapshot.position.lerp(next.snapshot.position, t),
+                rotation: first.snapshot.rotation.slerp(next.snapshot.rotation, t),
+                movement: first.snapshot.movement,
+                speed: first.snapshot.speed.lerp(next.snapshot.speed, t),
+            });
+        }
+
+        // Snapshots are late, keep the player going for a little while
+        let velocity =
+            (next.snapshot.position - first.snapshot.position) / (next.time - first.time) as f32;
+        let ahead = (time - next.time).min(MAX_EXTRAPOLATION);
+        Some(Snapshot {
+            position: next.snapshot.position + velocity * ahead as f32,
+            ..next.snapshot
+        })
+    }
 }
 
+// Movement state of a remote player or NPC at the time it is rendered, drives the animations
 #[derive(Component)]
-pub struct RemotePlayerPosition {
-    pub target_position: Vec3,
+pub struct RemotePlayerMovement {
+    pub movement: MovementState,
+    pub speed: f32,
 }
 
 #[derive(Component)]
-pub struct RemotePlayer;
+pub struct RemotePlayer {
+    // Id of the character
+    pub id: u64,
+}
 
 #[derive(Component, Reflect)]
 pub struct Health {
@@ -60,18 +241,48 @@ impl Percentage for Mana {
     }
 }
 
-impl RemotePlayersRegistry {
-    pub fn register(&mut self, id: Identity, entity: Entity) {
-        self.entities.insert(id, entity);
+impl<T: Send + Sync + 'static> SnapshotsRegistry<T> {
+    pub fn register(&mut self, id: u64, entity: Entity) {
+        self.entities.insert(
+            id,
+            RemoteEntry {
+                entity,
+                snapshots: HashMap::default(),
+            },
+        );
     }
 
-    pub fn get_entity(&self, id: &Identity) -> Option<Entity> {
-        self.entities.get(id).copied()
+    pub fn get_entity(&self, id: &u64) -> Option<Entity> {
+        self.entities.get(id).map(|entry| entry.entity)
     }
 
-    pub fn remove(&mut self, id: &Identity) {
+    pub fn remove(&mut self, id: &u64) {
         self.entities.remove(id);
     }
+
+    pub fn add_snapshot(&mut self, id: &u64, snapshot_id: u64, ring: u8) {
+        if let Some(entry) = self.entities.get_mut(id) {
+            entry.snapshots.insert(snapshot_id, ring);
+        }
+    }
+
+    // Returns the number of snapshots still visible for the player
+    pub fn remove_snapshot(&mut self, id: &u64, snapshot_id: u64) -> usize {
+        self.entities.get_mut(id).map_or(0, |entry| {
+            entry.snapshots.remove(&snapshot_id);
+            entry.snapshots.len()
+        })
+    }
+
+    // Whether the snapshot is the most detailed one visible for the player
+    pub fn is_finest_snapshot(&self, id: &u64, ring: u8) -> bool {
+        self.entities.get(id).is_some_and(|entry| {
+            entry
+                .snapshots
+                .values()
+                .all(|visible_ring| ring <= *visible_ring)
+        })
+    }
 }
 
 pub struct RemotePlayersPlugin;
@@ -83,6 +294,7 @@ impl Plugin for RemotePlayersPlugin {
             HealthBarPlugin::<Mana>::default(),
         ))
         .init_resource::<RemotePlayersRegistry>()
+        .init_resource::<ServerClock>()
         .insert_resource(
             ColorScheme::<Mana>::new().foreground_color(ForegroundColor::Static(BLUE.into())),
         )
@@ -95,30 +307,72 @@ impl Plugin for RemotePlayersPlugin {
                 .in_set(InGameSet)
                 .chain(),
         )
-        .add_systems(PostUpdate, lerp_remote_players.in_set(InGameSet))
-        .add_systems(Update, on_remote_player_position_updated.in_set(InGameSet));
+        .add_systems(PostUpdate, interpolate_remote_players.in_set(InGameSet))
+        .add_systems(
+            Update,
+            (on_remote_player_position_updated, on_remote_player_updated).in_set(InGameSet),
+        );
     }
 }
 
 fn on_remote_player_position_inserted(
     mut commands: Commands,
     mut registry: ResMut<RemotePlayersRegistry>,
-    mut events: ReadInsertEvent<PlayerPosition>,
+    mut events: ReadInsertEvent<PlayerPositionLod>,
+    mut clock: ResMut<ServerClock>,
+    mut buffers: Query<&mut SnapshotBuffer>,
+    time: Res<Time<Real>>,
     models: Res<CharacterAssets>,
     nameplates: Res<NameplateAssets>,
     conn: Res<StdbConnection<DbConnection>>,
 ) {
     for event in events.read() {
-        if event.row.id == conn.identity() {
+        let row = &event.row;
+        if Some(row.player_id) == current_character(&conn) {
             continue;
         }
 
-        info!("Remote player position inserted: {:?}", event.row.id);
-        let player = conn.db().players().id().find(&conn.identity()).unwrap();
+        clock.observe(snapshot_time(row), time.elapsed_secs_f64());
+
+        if registry.get_entity(&row.player_id).is_some() {
+            registry.add_snapshot(&row.player_id, row.id, row.ring);
+            if registry.is_finest_snapshot(&row.player_id, row.ring) {
+                push_snapshot(&registry, &mut buffers, row);
+            }
+            continue;
+        }
+
+        info!("Remote player position inserted: {:?}", row.player_id);
+        // The players row may only arrive after the position, the stats are filled in then
+        let player = conn.db().players().id().find(&row.player_id);
+        let (health, mana, name) = match &player {
+            Some(player) => (
+                Health {
+                    current: player.health,
+                    max: player.max_health,
+                },
+                Mana {
+                    current: player.mana,
+                    max: player.max_mana,
+                },
+                player.name.as_str(),
+            ),
+            None => (
+                Health {
+                    current: 0.0,
+                    max: 0.0,
+                },
+                Mana {
+                    current: 0.0,
+                    max: 0.0,
+                },
+                "",
+            ),
+        };
 
         let entity = commands
             .spawn((
-                Name::new(format!("RemotePlayer#{}", player.id.to_abbreviated_hex())),
+                Name::new(format!("RemotePlayer#{}", row.player_id)),
                 Visibility::Visible,
                 RigidBody::Kinematic,
                 Collider::capsule_endpoints(
@@ -126,23 +380,24 @@ fn on_remote_player_position_inserted(
                     Vec3::new(0.0, 0.0, 0.0),
                     Vec3::new(0.0, 1.0, 0.0),
                 ),
-                Transform::from_xyz(event.row.x, event.row.y, event.row.z),
-                RemotePlayer,
+                Transform::from_xyz(row.x, row.y, row.z)
+                    .with_rotation(Quat::from_rotation_y(row.yaw)),
+                SnapshotBuffer::new(snapshot_time(row), Snapshot::from_row(row)),
+                RemotePlayerMovement {
+                    movement: row.movement,
+                    speed: row.speed,
+                },
+                TnuaAnimatingState::<PlayerAnimationState>::default(),
+                RemotePlayer { id: row.player_id },
                 (
-                    Health {
-                        current: player.health,
-                        max: player.max_health,
-                    },
+                    health,
                     BarSettings::<Health> {
                         offset: 1.6,
                         height: BarHeight::Static(0.05),
                         width: 1.0,
                         ..default()
                     },
-                    Mana {
-                        current: player.mana,
-                        max: player.max_mana,
-                    },
+                    mana,
                     BarSettings::<Mana> {
                         offset: 1.5,
                         height: BarHeight::Static(0.05),
@@ -150,68 +405,143 @@ fn on_remote_player_position_inserted(
                         ..default()
                     },
                 ),
-                children![(
-                    SceneRoot(models.character_scene.clone()),
-                    Transform::from_xyz(0.0, -0.5, 0.0)
-                ),],
+                children![
+                    (
+                        SceneRoot(models.character_scene.clone()),
+                        Transform::from_xyz(0.0, -0.5, 0.0)
+                    ),
+                    nameplate(name, &nameplates),
+                ],
             ))
             .id();
 
-        registry.register(event.row.id, entity);
+        registry.register(row.player_id, entity);
+        registry.add_snapshot(&row.player_id, row.id, row.ring);
     }
 }
 
 fn on_remote_player_position_deleted(
     mut commands: Commands,
     mut registry: ResMut<RemotePlayersRegistry>,
-    mut events: ReadDeleteEvent<PlayerPosition>,
+    mut events: ReadDeleteEvent<PlayerPositionLod>,
     conn: Res<StdbConnection<DbConnection>>,
 ) {
     for event in events.read() {
-        if event.row.id == conn.identity() {
+        let row = &event.row;
+        if Some(row.player_id) == current_character(&conn) {
             continue;
         }
 
-        info!("Remote player position deleted: {:?}", event.row.id);
-
-        if let Some(remote_player_entity) = registry.get_entity(&event.row.id) {
-            commands.entity(remote_player_entity).despawn();
-            registry.remove(&event.row.id);
-        } else {
+        let Some(remote_player_entity) = registry.get_entity(&row.player_id) else {
             warn!(
                 "Remote player position deleted for unknown entity: {}",
-                event.row.id.to_abbreviated_hex()
+                row.player_id
             );
+            continue;
+        };
+
+        if registry.remove_snapshot(&row.player_id, row.id) > 0 {
+            continue;
         }
+
+        info!("Remote player position deleted: {:?}", row.player_id);
+        commands.entity(remote_player_entity).despawn();
+        registry.remove(&row.player_id);
     }
 }
 
 fn on_remote_player_position_updated(
-    mut commands: Commands,
-    registry: ResMut<RemotePlayersRegistry>,
-    mut events: ReadUpdateEvent<PlayerPosition>,
+    registry: Res<RemotePlayersRegistry>,
+    mut events: ReadUpdateEvent<PlayerPositionLod>,
+    mut clock: ResMut<ServerClock>,
+    mut buffers: Query<&mut SnapshotBuffer>,
+    time: Res<Time<Real>>,
 ) {
     for event in events.read() {
         let row = &event.new;
-        if let Some(entity) = registry.get_entity(&row.id) {
-            commands.entity(entity).insert(RemotePlayerPosition {
-                target_position: Vec3::new(row.x, row.y, row.z),
-            });
+        clock.observe(snapshot_time(row), time.elapsed_secs_f64());
+
+        if registry.is_finest_snapshot(&row.player_id, row.ring) {
+            push_snapshot(&registry, &mut buffers, row);
         }
     }
 }
 
-fn lerp_remote_players(time: Res<Time>, mut query: Query<(&mut Transform, &RemotePlayerPosition)>) {
-    for (mut transform, remote_player) in query.iter_mut() {
-        let delta = remote_player.target_position - transform.translation;
-        if delta.length() <= 0.01 {
+// Keep the bars and nameplate of remote players in sync with their players row,
+// which can also be inserted after the player was spawned from its position
+fn on_remote_player_updated(
+    registry: Res<RemotePlayersRegistry>,
+    mut inserted: ReadInsertEvent<Player>,
+    mut updated: ReadUpdateEvent<Player>,
+    mut stats: Query<(&mut Health, &mut Mana)>,
+    children: Query<&Children>,
+    mut nameplates: Query<&mut BillboardText, With<Nameplate>>,
+) {
+    let rows = inserted
+        .read()
+        .map(|event| &event.row)
+        .chain(updated.read().map(|event| &event.new));
+    for row in rows {
+        let Some(entity) = registry.get_entity(&row.id) else {
             continue;
+        };
+
+        if let Ok((mut health, mut mana)) = stats.get_mut(entity) {
+            health.current = row.health;
+            health.max = row.max_health;
+            mana.current = row.mana;
+            mana.max = row.max_mana;
         }
 
-        let current_translation = transform.translation;
-        let target_translation = remote_player.target_position;
-        let t = 1.0 - (-PLAYER_WALK_SPEED * time.delta_secs()).exp();
+        for child in children.iter_descendants(entity) {
+            if let Ok(mut text) = nameplates.get_mut(child) {
+                if text.0 != row.name {
+                    text.0 = row.name.clone();
+                }
+            }
+        }
+    }
+}
+
+// Server time of a snapshot, in seconds
+fn snapshot_time(row: &PlayerPositionLod) -> f64 {
+    row.sampled_at.to_micros_since_unix_epoch() as f64 / 1_000_000.0
+}
 
-        transform.translation = current_translation.lerp(target_translation, t);
+fn push_snapshot(
+    registry: &RemotePlayersRegistry,
+    buffers: &mut Query<&mut SnapshotBuffer>,
+    row: &PlayerPositionLod,
+) {
+    let Some(entity) = registry.get_entity(&row.player_id) else {
+        return;
+    };
+
+    if let Ok(mut buffer) = buffers.get_mut(entity) {
+        buffer.push(snapshot_time(row), Snapshot::from_row(row));
+    }
+}
+
+fn interpolate_remote_players(
+    time: Res<Time<Real>>,
+    clock: Res<ServerClock>,
+    mut query: Query<(
+        &mut Transform,
+        &mut RemotePlayerMovement,
+        &mut SnapshotBuffer,
+    )>,
+) {
+    let Some(server_now) = clock.now(time.elapsed_secs_f64()) else {
+        return;
+    };
+
+    for (mut transform, mut movement, mut buffer) in query.iter_mut() {
+        let render_time = server_now - buffer.delay();
+        if let Some(snapshot) = buffer.sample(render_time) {
+            transform.translation = snapshot.position;
+            transform.rotation = snapshot.rotation;
+            movement.movement = snapshot.movement;
+            movement.speed = snapshot.speed;
+        }
     }
 }
@@ -1,12 +1,19 @@
 use bevy::prelude::*;
 use bevy_spacetimedb::{
-    ReadStdbConnectedEvent, ReadStdbDisconnectedEvent, StdbConnectedEvent, StdbConnection,
-    StdbConnectionErrorEvent, StdbDisconnectedEvent, StdbPlugin, tables,
+    ReadStdbConnectedEvent, ReadStdbConnectionErrorEvent, ReadStdbDisconnectedEvent,
+    StdbConnectedEvent, StdbConnection, StdbConnectionErrorEvent, StdbDisconnectedEvent,
+    StdbPlugin, tables,
 };
 
 use bindings::{
-    DbConnection, PlayersPositionsLrTableAccess, PlayersPositionsTableAccess, PlayersTableAccess,
+    AbilitiesCooldownsTableAccess, AbilitiesTableAccess, CharactersTableAccess,
+    ChatMessagesTableAccess, DbConnection, EquippedItemsTableAccess, InventorySlotsTableAccess,
+    ItemDefinitionsTableAccess, MovementViolationsTableAccess, NpcTypesTableAccess,
+    NpcsPositionsLodsTableAccess, NpcsTableAccess, PlayersInputsAcksTableAccess,
+    PlayersPositionsLodsTableAccess, PlayersTableAccess, SessionsTableAccess,
+    WorldItemsTableAccess,
 };
+use spacetimedb_sdk::DbContext;
 
 use crate::state::GameState;
 
@@ -43,41 +50,113 @@ impl Plugin for ServerPlugin {
                 conn
             })
             .with_events(|plugin, app, db, _| {
-                tables!(players, players_positions_lr, players_positions);
+                tables!(
+                    players,
+                    players_positions_lods,
+                    movement_violations,
+                    players_inputs_acks,
+                    characters,
+                    sessions,
+                    abilities,
+                    abilities_cooldowns,
+                    chat_messages,
+                    item_definitions,
+                    inventory_slots,
+                    equipped_items,
+                    world_items,
+                    npc_types,
+                    npcs,
+                    npcs_positions_lods
+                );
             });
         app.add_plugins(plugin);
 
-        app.add_systems(First, on_connected)
+        app.init_resource::<ConnectionStatus>()
+            .add_systems(First, (on_connected, on_connection_error))
             .add_systems(Last, on_disconnected)
             .add_systems(OnEnter(GameState::InGame), subscribe_to_world);
     }
 }
 
-fn on_connected(mut events: ReadStdbConnectedEvent) {
+// State of the connection to SpacetimeDB, the menus wait on it before listing the characters
+#[derive(Resource, Default)]
+pub struct ConnectionStatus {
+    pub connected: bool,
+    pub error: Option<String>,
+}
+
+fn on_connected(mut events: ReadStdbConnectedEvent, mut status: ResMut<ConnectionStatus>) {
     for _ in events.read() {
         info!("Connected to SpacetimeDB");
+        status.connected = true;
     }
 }
 
-fn on_disconnected(mut events: ReadStdbDisconnectedEvent) {
-    for _ in events.read() {
+fn on_connection_error(
+    mut events: ReadStdbConnectionErrorEvent,
+    mut status: ResMut<ConnectionStatus>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    for event in events.read() {
+        error!("Could not connect to SpacetimeDB: {}", event.err);
+        status.connected = false;
+        status.error = Some(format!("Could not connect to the server: {}", event.err));
+        next_state.set(GameState::ConnectionFailed);
+    }
+}
+
+fn on_disconnected(
+    mut events: ReadStdbDisconnectedEvent,
+    mut status: ResMut<ConnectionStatus>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    for event in events.read() {
         info!("Disconnected from SpacetimeDB");
+        status.connected = false;
+        status.error = Some(match &event.err {
+            Some(err) => format!("Disconnected from the server: {}", err),
+            None => "Disconnected from the server".to_string(),
+        });
+        next_state.set(GameState::ConnectionFailed);
     }
 }
 
+// Only subscribed once the session of the selected character is open, the hot tables are keyed by it
 fn subscribe_to_world(conn: ResMut<StdbConnection<DbConnection>>) {
     info!("Subscribing to world tables");
 
     let queries = [
         "SELECT * FROM players",
-        "SELECT * FROM players_positions",
-        "SELECT * FROM players_positions_lr",
+        "SELECT * FROM players_positions_lods",
+        "SELECT * FROM movement_violations",
+        "SELECT * FROM players_inputs_acks",
+        "SELECT * FROM abilities",
+        "SELECT * FROM abilities_cooldowns",
+        "SELECT * FROM chat_messages",
+        "SELECT * FROM item_definitions",
+        "SELECT * FROM inventory_slots",
+        "SELECT * FROM equipped_items",
+        "SELECT * FROM world_items",
+        "SELECT * FROM npc_types",
+        "SELECT * FROM npcs",
+        "SELECT * FROM npcs_positions_lods",
     ];
 
     conn.subscribe()
-        .on_applied(|_| info!("Subscribed to world"))
+        .on_applied(|_| {
+            info!("Subscribed to world");
+        })
         .on_error(|_, err| {
             panic!("Error while subscribing to world: {}", err);
         })
         .subscribe(queries);
 }
+
+// Id of the character played by this connection, hot tables are keyed by it
+pub fn current_character(conn: &StdbConnection<DbConnection>) -> Option<u64> {
+    conn.db()
+        .sessions()
+        .identity()
+        .find(&conn.identity())
+        .map(|session| session.character_id)
+}
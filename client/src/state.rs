@@ -4,7 +4,15 @@ use bevy::prelude::*;
 pub enum GameState {
     #[default]
     LoadingWorld,
+    // Waiting for the connection and the account's characters
+    Connecting,
+    CharacterSelect,
+    CharacterCreate,
+    // Character selected, waiting for the server to open its session
+    EnteringWorld,
     InGame,
+    // The connection could not be established or was lost
+    ConnectionFailed,
 }
 
 #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
@@ -0,0 +1,161 @@
+use bevy::{platform::collections::HashMap, prelude::*};
+use bevy_spacetimedb::{ReadDeleteEvent, ReadInsertEvent, StdbConnection};
+use bindings::{DbConnection, ItemDefinitionsTableAccess, WorldItem, pick_up_item};
+use leafwing_input_manager::prelude::ActionState;
+use spacetimedb_sdk::{DbContext, Status};
+
+use crate::{
+    input::Actions,
+    load_world::NameplateAssets,
+    local_player::LocalPlayer,
+    nameplates::nameplate,
+    state::{GameState, InGameSet},
+};
+
+// Must match ServerConfig::pickup_range on the server
+const PICKUP_RANGE: f32 = 3.0;
+// Items float a little above the ground and spin so that they stand out
+const ITEM_HOVER_HEIGHT: f32 = 0.3;
+const ITEM_SPIN_SPEED: f32 = 1.5;
+
+#[derive(Resource)]
+struct WorldItemAssets {
+    mesh: Handle<Mesh>,
+    material: Handle<StandardMaterial>,
+}
+
+// Entities of the items on the ground by row id
+#[derive(Resource, Default)]
+struct WorldItemsRegistry {
+    entities: HashMap<u64, Entity>,
+}
+
+#[derive(Component)]
+pub struct WorldItemPickup {
+    pub id: u64,
+}
+
+pub struct WorldItemsPlugin;
+
+impl Plugin for WorldItemsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<WorldItemsRegistry>()
+            .add_systems(Startup, create_world_item_assets)
+            .add_systems(OnEnter(GameState::InGame), register_pick_up_callback)
+            .add_systems(
+                PreUpdate,
+                (on_world_item_inserted, on_world_item_deleted)
+                    .chain()
+                    .in_set(InGameSet),
+            )
+            .add_systems(
+                Update,
+                (spin_world_items, pick_up_closest_item).in_set(InGameSet),
+            );
+    }
+}
+
+fn create_world_item_assets(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.insert_resource(WorldItemAssets {
+        mesh: meshes.add(Cuboid::new(0.3, 0.3, 0.3)),
+        material: materials.add(StandardMaterial {
+            base_color: Color::srgb(0.9, 0.75, 0.2),
+            emissive: LinearRgba::rgb(0.3, 0.25, 0.05),
+            ..default()
+        }),
+    });
+}
+
+// Pick ups are validated by the server, report the ones it refused
+fn register_pick_up_callback(conn: Res<StdbConnection<DbConnection>>) {
+    conn.reducers().on_pick_up_item(|ctx, world_item_id| {
+        if ctx.event.caller_identity != ctx.identity() {
+            return;
+        }
+        if let Status::Failed(err) = &ctx.event.status {
+            warn!("Could not pick up item {}: {}", world_item_id, err);
+        }
+    });
+}
+
+fn on_world_item_inserted(
+    mut commands: Commands,
+    mut registry: ResMut<WorldItemsRegistry>,
+    mut events: ReadInsertEvent<WorldItem>,
+    assets: Res<WorldItemAssets>,
+    nameplates: Res<NameplateAssets>,
+    conn: Res<StdbConnection<DbConnection>>,
+) {
+    for event in events.read() {
+        let row = &event.row;
+        let name = conn
+            .db()
+            .item_definitions()
+            .id()
+            .find(&row.item_id)
+            .map_or("?".to_string(), |item| item.name);
+        let label = if row.quantity > 1 {
+            format!("{} x{}", name, row.quantity)
+        } else {
+            name
+        };
+
+        let entity = commands
+            .spawn((
+                WorldItemPickup { id: row.id },
+                Name::new(format!("World item {}", row.id)),
+                Mesh3d(assets.mesh.clone()),
+                MeshMaterial3d(assets.material.clone()),
+                Transform::from_xyz(row.x, row.y + ITEM_HOVER_HEIGHT, row.z),
+                children![nameplate(&label, &nameplates)],
+            ))
+            .id();
+        registry.entities.insert(row.id, entity);
+    }
+}
+
+fn on_world_item_deleted(
+    mut commands: Commands,
+    mut registry: ResMut<WorldItemsRegistry>,
+    mut events: ReadDeleteEvent<WorldItem>,
+) {
+    for event in events.read() {
+        if let Some(entity) = registry.entities.remove(&event.row.id) {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+fn spin_world_items(time: Res<Time>, mut items: Query<&mut Transform, With<WorldItemPickup>>) {
+    for mut transform in &mut items {
+        transform.rotate_y(ITEM_SPIN_SPEED * time.delta_secs());
+    }
+}
+
+// Pick up the closest item within reach
+fn pick_up_closest_item(
+    player: Single<(&Transform, &ActionState<Actions>), With<LocalPlayer>>,
+    items: Query<(&WorldItemPickup, &Transform)>,
+    conn: Res<StdbConnection<DbConnection>>,
+) {
+    let (player_transform, actions) = player.into_inner();
+    if !actions.just_pressed(&Actions::PickUp) {
+        return;
+    }
+
+    let closest = items
+        .iter()
+        .map(|(item, transform)| {
+            let distance = transform.translation.distance(player_transform.translation);
+            (item.id, distance)
+        })
+        .filter(|(_, distance)| *distance <= PICKUP_RANGE)
+        .min_by(|(_, a), (_, b)| a.total_cmp(b));
+    if let Some((id, _)) = closest {
+        conn.reducers().pick_up_item(id).unwrap();
+    }
+}
@@ -0,0 +1,34 @@
+use spacetimedb::{reducer, Identity, ReducerContext, Table};
+
+use crate::tables::accounts::{admins, Admin};
+
+#[reducer]
+fn add_admin(ctx: &ReducerContext, identity: Identity) -> Result<(), String> {
+    ensure_admin(ctx)?;
+    if ctx.db.admins().identity().find(identity).is_some() {
+        return Err(format!("{} is already an admin", identity));
+    }
+
+    log::info!("{} made {} an admin", ctx.sender, identity);
+    ctx.db.admins().insert(Admin {
+        identity,
+        added_at: ctx.timestamp,
+    });
+    Ok(())
+}
+
+// Whether the sender may run the reducers reserved to game masters and designers
+pub fn ensure_admin(ctx: &ReducerContext) -> Result<(), String> {
+    if ctx.db.admins().identity().find(ctx.sender).is_none() {
+        return Err("Only admins can do this".to_string());
+    }
+    Ok(())
+}
+
+// Scheduled reducers can be called by clients like any other reducer, only let the module run them
+pub fn ensure_scheduler(ctx: &ReducerContext) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("Only the scheduler can do this".to_string());
+    }
+    Ok(())
+}
@@ -0,0 +1,333 @@
+use spacetimedb::{reducer, ReducerContext, Table, Timestamp};
+
+use crate::{
+    inventory::give_item,
+    players::insert_hot_data,
+    tables::{
+        abilities::{abilities_cooldowns, AbilityCooldown},
+        accounts::{characters, sessions, Character, CharacterClass, Session},
+        config::{LodRing, ServerConfig},
+        items::{equipped_items, inventory_slots, ItemDefinition},
+        movement_violations::movement_violations,
+        players::{players, players_respawns, Player},
+        players_inputs::{players_inputs, players_inputs_acks},
+        players_positions::{players_positions, players_positions_lods, PlayerPosition},
+        players_windows::{players_windows, players_windows_cells},
+    },
+};
+
+const MAX_CHARACTERS: usize = 8;
+const NAME_MIN_LENGTH: usize = 3;
+const NAME_MAX_LENGTH: usize = 16;
+// Items new characters start with
+const STARTER_ITEMS: [(u32, u32); 2] = [
+    (ItemDefinition::HEALTH_POTION, 3),
+    (ItemDefinition::MANA_POTION, 3),
+];
+
+#[reducer]
+fn create_character(
+    ctx: &ReducerContext,
+    name: String,
+    class: CharacterClass,
+) -> Result<(), String> {
+    let name = normalize_name(&name)?;
+
+    if ctx.db.characters().account().filter(&ctx.sender).count() >= MAX_CHARACTERS {
+        return Err(format!(
+            "Accounts are limited to {} characters",
+            MAX_CHARACTERS
+        ));
+    }
+    if ctx.db.characters().name().find(&name).is_some() {
+        return Err(format!("The name {} is already taken", name));
+    }
+
+    let character = ctx.db.characters().insert(Character {
+        id: 0,
+        account: ctx.sender,
+        name,
+        class,
+        created_at: ctx.timestamp,
+        last_login: ctx.timestamp,
+    });
+    ctx.db
+        .players()
+        .insert(Player::new(character.id, character.name.clone()));
+    for (item_id, quantity) in STARTER_ITEMS {
+        give_item(ctx, character.id, item_id, quantity)?;
+    }
+
+    log::info!("Character {} created by {}", character.name, ctx.sender);
+    Ok(())
+}
+
+#[reducer]
+fn delete_character(ctx: &ReducerContext, character_id: u64) -> Result<(), String> {
+    let character = owned_character(ctx, character_id)?;
+    if ctx
+        .db
+        .sessions()
+        .character_id()
+        .find(character.id)
+        .is_some()
+    {
+        return Err(format!("{} is in the world", character.name));
+    }
+
+    ctx.db.characters().id().delete(character.id);
+    ctx.db.players().id().delete(character.id);
+    ctx.db.players_respawns().player_id().delete(character.id);
+    ctx.db.inventory_slots().owner().delete(character.id);
+    ctx.db.equipped_items().owner().delete(character.id);
+
+    log::info!("Character {} deleted by {}", character.name, ctx.sender);
+    Ok(())
+}
+
+// Enter the world with one of the sender's characters, leaving the current one if any
+#[reducer]
+fn select_character(ctx: &ReducerContext, character_id: u64) -> Result<(), String> {
+    let mut character = owned_character(ctx, character_id)?;
+    if let Some(session) = ctx.db.sessions().identity().find(ctx.sender) {
+        end_session(ctx, &session);
+    }
+
+    let mut player = ctx
+        .db
+        .players()
+        .id()
+        .find(character.id)
+        .ok_or(format!("{} has no player", character.name))?;
+
+    // Hot data left without a session can't be trusted, start over from cold storage
+    clear_hot_data(ctx, character.id);
+
+    ctx.db.sessions().insert(Session {
+        identity: ctx.sender,
+        character_id: character.id,
+        started_at: ctx.timestamp,
+    });
+
+    let position = enter_world(&mut player, ctx.timestamp);
+    ctx.db.players().id().update(player);
+
+    character.last_login = ctx.timestamp;
+    ctx.db.characters().id().update(character);
+
+    let config = ServerConfig::get(ctx);
+    let rings = LodRing::all(ctx);
+    insert_hot_data(
+        ctx,
+        position,
+        ctx.sender,
+        config.default_view_distance,
+        &config,
+        &rings,
+    );
+
+    Ok(())
+}
+
+// Id of the character the sender is playing
+pub fn current_character(ctx: &ReducerContext) -> Result<u64, String> {
+    ctx.db
+        .sessions()
+        .identity()
+        .find(ctx.sender)
+        .map(|session| session.character_id)
+        .ok_or("No character selected".to_string())
+}
+
+// Save the character to cold storage and remove it from the world
+pub fn end_session(ctx: &ReducerContext, session: &Session) {
+    let position = ctx.db.players_positions().id().find(session.character_id);
+    match ctx.db.players().id().find(session.character_id) {
+        Some(mut player) => {
+            if position.is_none() {
+                log::warn!(
+                    "Character {} left without a position, keeping the last saved one",
+                    session.character_id
+                );
+            }
+            leave_world(&mut player, position.as_ref());
+            ctx.db.players().id().update(player);
+        }
+        None => log::warn!("Character {} left without a player", session.character_id),
+    }
+
+    clear_hot_data(ctx, session.character_id);
+    ctx.db.sessions().identity().delete(session.identity);
+}
+
+// Mark the player online, its hot position starts from the last saved one
+fn enter_world(player: &mut Player, now: Timestamp) -> PlayerPosition {
+    player.online = true;
+    PlayerPosition::from_player(player, now)
+}
+
+// Mark the player offline, saving where it stands when it is still in the world
+fn leave_world(player: &mut Player, position: Option<&PlayerPosition>) {
+    player.online = false;
+    if let Some(position) = position {
+        player.save_position(position);
+    }
+}
+
+// Remove everything kept about a character while it is in the world
+pub fn clear_hot_data(ctx: &ReducerContext, character_id: u64) {
+    ctx.db.players_windows().id().delete(character_id);
+    ctx.db
+        .players_windows_cells()
+        .player_id()
+        .delete(character_id);
+    ctx.db.players_positions().id().delete(character_id);
+    ctx.db
+        .movement_violations()
+        .player_id()
+        .delete(character_id);
+    ctx.db.players_inputs().player_id().delete(character_id);
+    ctx.db
+        .players_inputs_acks()
+        .player_id()
+        .delete(character_id);
+    ctx.db
+        .players_positions_lods()
+        .player_id()
+        .delete(character_id);
+    // Running cooldowns survive the session, otherwise relogging would reset them
+    let cooldowns: Vec<AbilityCooldown> = ctx
+        .db
+        .abilities_cooldowns()
+        .player_id()
+        .filter(character_id)
+        .collect();
+    for id in elapsed_cooldowns(&cooldowns, ctx.timestamp) {
+        ctx.db.abilities_cooldowns().id().delete(id);
+    }
+}
+
+// Ids of the cooldowns that no longer prevent casting
+fn elapsed_cooldowns(cooldowns: &[AbilityCooldown], now: Timestamp) -> Vec<u64> {
+    cooldowns
+        .iter()
+        .filter(|cooldown| cooldown.is_ready(now))
+        .map(|cooldown| cooldown.id)
+        .collect()
+}
+
+fn owned_character(ctx: &ReducerContext, character_id: u64) -> Result<Character, String> {
+    ctx.db
+        .characters()
+        .id()
+        .find(character_id)
+        .filter(|character| character.account == ctx.sender)
+        .ok_or(format!("Character {} not found", character_id))
+}
+
+// Names are 3 to 16 ASCII letters, capitalized so that uniqueness ignores the case
+pub fn normalize_name(name: &str) -> Result<String, String> {
+    let name = name.trim();
+    if name.len() < NAME_MIN_LENGTH || name.len() > NAME_MAX_LENGTH {
+        return Err(format!(
+            "Names must be between {} and {} letters long",
+            NAME_MIN_LENGTH, NAME_MAX_LENGTH
+        ));
+    }
+    if !name.chars().all(|c| c.is_ascii_alphabetic()) {
+        return Err("Names can only contain letters".to_string());
+    }
+
+    let lowercase = name.to_ascii_lowercase();
+    Ok(lowercase[..1].to_ascii_uppercase() + &lowercase[1..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at_ms(ms: i64) -> Timestamp {
+        Timestamp::from_micros_since_unix_epoch(ms * 1000)
+    }
+
+    fn new_player() -> Player {
+        Player::new(1, "Aria".to_string())
+    }
+
+    #[test]
+    fn first_login_enters_at_the_origin() {
+        let mut player = new_player();
+        let position = enter_world(&mut player, at_ms(0));
+
+        assert!(player.online);
+        assert_eq!(position.id, player.id);
+        assert_eq!((position.x, position.y, position.z), (0.0, 0.0, 0.0));
+        assert_eq!(position.last_update, at_ms(0));
+        assert!(!position.dirty);
+    }
+
+    #[test]
+    fn relog_restores_the_position_saved_when_leaving() {
+        let mut player = new_player();
+        let mut position = enter_world(&mut player, at_ms(0));
+        position.x = 12.0;
+        position.z = -4.0;
+        leave_world(&mut player, Some(&position));
+        assert!(!player.online);
+
+        let position = enter_world(&mut player, at_ms(60_000));
+        assert!(player.online);
+        assert_eq!((position.x, position.z), (12.0, -4.0));
+        assert_eq!(position.last_update, at_ms(60_000));
+    }
+
+    #[test]
+    fn relog_after_losing_the_hot_position_keeps_the_autosave() {
+        let mut player = new_player();
+        let mut position = enter_world(&mut player, at_ms(0));
+        position.x = 3.0;
+        player.save_position(&position);
+
+        leave_world(&mut player, None);
+        assert!(!player.online);
+        let position = enter_world(&mut player, at_ms(60_000));
+        assert_eq!(position.x, 3.0);
+    }
+
+    #[test]
+    fn relog_keeps_the_running_cooldowns() {
+        let cooldown = |id, ability_id, ready_ms| AbilityCooldown {
+            id,
+            player_id: 1,
+            ability_id,
+            ready_at: at_ms(ready_ms),
+        };
+        // Strike and Backstab cast at 0, the character is selected again 2 seconds later
+        let cooldowns = [cooldown(1, 1, 1_500), cooldown(2, 3, 8_000)];
+
+        assert_eq!(elapsed_cooldowns(&cooldowns, at_ms(2_000)), vec![1]);
+        assert_eq!(elapsed_cooldowns(&cooldowns, at_ms(8_000)), vec![1, 2]);
+    }
+
+    #[test]
+    fn names_are_capitalized() {
+        assert_eq!(normalize_name("aRIA"), Ok("Aria".to_string()));
+        assert_eq!(normalize_name("  bob "), Ok("Bob".to_string()));
+    }
+
+    #[test]
+    fn names_are_3_to_16_letters_long() {
+        assert!(normalize_name("Al").is_err());
+        assert!(normalize_name("Abc").is_ok());
+        assert!(normalize_name(&"a".repeat(16)).is_ok());
+        assert!(normalize_name(&"a".repeat(17)).is_err());
+        assert!(normalize_name("   ").is_err());
+    }
+
+    #[test]
+    fn names_only_contain_ascii_letters() {
+        assert!(normalize_name("Ar1a").is_err());
+        assert!(normalize_name("Ar ia").is_err());
+        assert!(normalize_name("Élise").is_err());
+    }
+}
@@ -0,0 +1,134 @@
+use std::time::Duration;
+
+use spacetimedb::{
+    log_stopwatch::LogStopwatch, reducer, Identity, ReducerContext, Table, TimeDuration,
+};
+
+use crate::{
+    admin::ensure_scheduler,
+    characters::{current_character, normalize_name},
+    grid::cell_at,
+    tables::{
+        accounts::{characters, sessions},
+        chat::{chat_messages, ChatChannel, ChatCleanup, ChatMessage},
+        config::ServerConfig,
+        players::players,
+        players_positions::players_positions,
+    },
+};
+
+const MAX_MESSAGE_LENGTH: usize = 256;
+// Players can send at most RATE_LIMIT_MESSAGES messages over RATE_LIMIT_WINDOW
+const RATE_LIMIT_MESSAGES: usize = 5;
+const RATE_LIMIT_WINDOW: Duration = Duration::from_secs(10);
+
+#[reducer]
+fn send_message(
+    ctx: &ReducerContext,
+    channel: ChatChannel,
+    recipient: Option<String>,
+    text: String,
+) -> Result<(), String> {
+    let character_id = current_character(ctx)?;
+    let text = text.trim();
+    if text.is_empty() {
+        return Err("Messages can't be empty".to_string());
+    }
+    if text.chars().count() > MAX_MESSAGE_LENGTH {
+        return Err(format!(
+            "Messages are limited to {} characters",
+            MAX_MESSAGE_LENGTH
+        ));
+    }
+    if text.chars().any(char::is_control) {
+        return Err("Messages can't contain control characters".to_string());
+    }
+
+    let window_start = ctx.timestamp - TimeDuration::from_duration(RATE_LIMIT_WINDOW);
+    let recent = ctx
+        .db
+        .chat_messages()
+        .sender()
+        .filter(&ctx.sender)
+        .filter(|message| message.sent_at > window_start)
+        .count();
+    if recent >= RATE_LIMIT_MESSAGES {
+        return Err("Too many messages, slow down".to_string());
+    }
+
+    let sender_name = ctx
+        .db
+        .players()
+        .id()
+        .find(character_id)
+        .ok_or("Player not found")?
+        .name;
+    let position = ctx
+        .db
+        .players_positions()
+        .id()
+        .find(character_id)
+        .ok_or("Player position not found")?;
+
+    let (recipient, recipient_name) = match channel {
+        ChatChannel::Whisper => {
+            let name = normalize_name(&recipient.ok_or("Whispers need a recipient")?)?;
+            let identity = online_identity(ctx, &name)?;
+            if identity == ctx.sender {
+                return Err("You can't whisper to yourself".to_string());
+            }
+            (identity, name)
+        }
+        _ => (Identity::ZERO, String::new()),
+    };
+
+    ctx.db.chat_messages().insert(ChatMessage {
+        id: 0,
+        channel,
+        sender_id: character_id,
+        sender_name,
+        sender: ctx.sender,
+        recipient,
+        recipient_name,
+        cell: cell_at(position.x, position.z),
+        global: channel == ChatChannel::Global,
+        local: channel == ChatChannel::Local,
+        text: text.to_string(),
+        sent_at: ctx.timestamp,
+    });
+
+    Ok(())
+}
+
+#[reducer]
+pub fn cleanup_chat_messages(ctx: &ReducerContext, _row: ChatCleanup) -> Result<(), String> {
+    ensure_scheduler(ctx)?;
+    let _stopwatch = LogStopwatch::new("cleanup_chat_messages");
+    let retention = Duration::from_millis(ServerConfig::get(ctx).chat_retention_ms);
+    let cutoff = ctx.timestamp - TimeDuration::from_duration(retention);
+
+    let expired: Vec<u64> = ctx
+        .db
+        .chat_messages()
+        .iter()
+        .filter(|message| message.sent_at < cutoff)
+        .map(|message| message.id)
+        .collect();
+    for id in &expired {
+        ctx.db.chat_messages().id().delete(id);
+    }
+
+    log::info!("cleanup_chat_messages: deleted {} messages", expired.len());
+    Ok(())
+}
+
+// Identity playing the character with the given name
+fn online_identity(ctx: &ReducerContext, name: &str) -> Result<Identity, String> {
+    ctx.db
+        .characters()
+        .name()
+        .find(name.to_string())
+        .and_then(|character| ctx.db.sessions().character_id().find(character.id))
+        .map(|session| session.identity)
+        .ok_or(format!("{} is not online", name))
+}
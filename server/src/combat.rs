@@ -0,0 +1,328 @@
+use std::time::Duration;
+
+use spacetimedb::{
+    log_stopwatch::LogStopwatch, reducer, ReducerContext, ScheduleAt, Table, TimeDuration,
+};
+
+use crate::{
+    admin::ensure_scheduler,
+    characters::current_character,
+    inventory::remove_quantity,
+    movement::GROUND_HEIGHT,
+    npcs::damage_npc,
+    players::teleport_player,
+    tables::{
+        abilities::{abilities, abilities_cooldowns, AbilityCooldown},
+        config::ServerConfig,
+        items::{inventory_slots, InventorySlot, ItemDefinition},
+        npcs::{npcs, npcs_positions},
+        players::{players, players_respawns, Player, PlayerRegenTick, PlayerRespawn},
+        players_inputs::players_inputs,
+        players_positions::players_positions,
+    },
+    world_items::spawn_world_item,
+};
+
+// Where dead players come back to life
+const SPAWN_POINT: (f32, f32, f32) = (0.0, GROUND_HEIGHT, 0.0);
+// Interval between two regeneration ticks
+pub const REGEN_INTERVAL: Duration = Duration::from_millis(1000);
+// Share of their gold coins players drop when they die
+const DEATH_GOLD_DROP_PERCENT: u32 = 50;
+
+#[reducer]
+fn cast_ability(ctx: &ReducerContext, target: u64, ability_id: u32) -> Result<(), String> {
+    let character_id = current_character(ctx)?;
+    if target == character_id {
+        return Err("Abilities can't target their caster".to_string());
+    }
+
+    let mut victim = alive_player(ctx, target)?;
+    // Positions only exist while the players are in the world
+    let target_position = ctx
+        .db
+        .players_positions()
+        .id()
+        .find(target)
+        .ok_or(format!("Target {} is not in the world", target))?;
+    let damage = use_ability(
+        ctx,
+        character_id,
+        ability_id,
+        (target_position.x, target_position.y, target_position.z),
+    )?;
+
+    damage_player(ctx, &mut victim, damage, Some(character_id));
+    log::info!(
+        "Player {} hit player {} for {}, {} health left",
+        character_id,
+        target,
+        damage,
+        victim.health
+    );
+    ctx.db.players().id().update(victim);
+
+    Ok(())
+}
+
+#[reducer]
+fn cast_ability_on_npc(ctx: &ReducerContext, npc_id: u64, ability_id: u32) -> Result<(), String> {
+    let character_id = current_character(ctx)?;
+    let npc = ctx
+        .db
+        .npcs()
+        .id()
+        .find(npc_id)
+        .ok_or(format!("NPC {} not found", npc_id))?;
+    let position = ctx
+        .db
+        .npcs_positions()
+        .id()
+        .find(npc_id)
+        .ok_or(format!("NPC {} position not found", npc_id))?;
+    let damage = use_ability(
+        ctx,
+        character_id,
+        ability_id,
+        (position.x, position.y, position.z),
+    )?;
+
+    damage_npc(ctx, npc, position, damage, character_id);
+    Ok(())
+}
+
+#[reducer]
+pub fn respawn_player(ctx: &ReducerContext, respawn: PlayerRespawn) -> Result<(), String> {
+    ensure_scheduler(ctx)?;
+    let mut player = ctx
+        .db
+        .players()
+        .id()
+        .find(respawn.player_id)
+        .ok_or(format!("Player {} not found", respawn.player_id))?;
+    // Respawning heals and teleports, it must never happen to a living player
+    if !player.dead {
+        return Err(format!("Player {} is not dead", player.id));
+    }
+
+    let (x, y, z) = SPAWN_POINT;
+    player.dead = false;
+    player.health = player.max_health;
+    player.mana = player.max_mana;
+    player.x = x;
+    player.y = y;
+    player.z = z;
+
+    // Players still in the world are moved right away, the others will enter at the spawn point
+    if let Some(mut position) = ctx.db.players_positions().id().find(player.id) {
+        teleport_player(ctx, &mut position, x, y, z);
+    }
+
+    log::info!("Player {} respawned", player.id);
+    ctx.db.players().id().update(player);
+    Ok(())
+}
+
+#[reducer]
+pub fn regenerate_players(ctx: &ReducerContext, _row: PlayerRegenTick) -> Result<(), String> {
+    ensure_scheduler(ctx)?;
+    let _stopwatch = LogStopwatch::new("regenerate_players");
+    let config = ServerConfig::get(ctx);
+    let interval = REGEN_INTERVAL.as_secs_f32();
+    let out_of_combat_delay = Duration::from_millis(config.out_of_combat_delay_ms);
+    let mut total = 0;
+    let mut updated = 0;
+
+    for mut player in ctx.db.players().online().filter(&true) {
+        total += 1;
+        if player.dead {
+            continue;
+        }
+
+        let out_of_combat = ctx
+            .timestamp
+            .duration_since(player.last_combat)
+            .is_some_and(|elapsed| elapsed >= out_of_combat_delay);
+        let multiplier = if out_of_combat {
+            config.out_of_combat_regen_multiplier
+        } else {
+            1.0
+        };
+
+        let health = (player.health + config.health_regen_per_second * multiplier * interval)
+            .min(player.max_health);
+        let mana = (player.mana + config.mana_regen_per_second * multiplier * interval)
+            .min(player.max_mana);
+        // Full players are left alone so that idle crowds cost no writes
+        if health == player.health && mana == player.mana {
+            continue;
+        }
+
+        player.health = health;
+        player.mana = mana;
+        ctx.db.players().id().update(player);
+        updated += 1;
+    }
+
+    log::debug!(
+        "regenerate_players: updated {} of {} players",
+        updated,
+        total
+    );
+    Ok(())
+}
+
+// Check that the caster can use the ability on a target standing at the given position,
+// then spend its mana and start the cooldown. Returns the damage dealt
+fn use_ability(
+    ctx: &ReducerContext,
+    character_id: u64,
+    ability_id: u32,
+    (x, y, z): (f32, f32, f32),
+) -> Result<f32, String> {
+    let ability = ctx
+        .db
+        .abilities()
+        .id()
+        .find(ability_id)
+        .ok_or(format!("Ability {} not found", ability_id))?;
+    let mut caster = alive_player(ctx, character_id)?;
+
+    let caster_position = ctx
+        .db
+        .players_positions()
+        .id()
+        .find(character_id)
+        .ok_or("Caster position not found")?;
+    let dx = x - caster_position.x;
+    let dy = y - caster_position.y;
+    let dz = z - caster_position.z;
+    if dx * dx + dy * dy + dz * dz > ability.range * ability.range {
+        return Err(format!("{} is out of range", ability.name));
+    }
+
+    let cooldown = ctx
+        .db
+        .abilities_cooldowns()
+        .player_id()
+        .filter(&character_id)
+        .find(|cooldown| cooldown.ability_id == ability_id);
+    if cooldown.is_some_and(|cooldown| !cooldown.is_ready(ctx.timestamp)) {
+        return Err(format!("{} is not ready", ability.name));
+    }
+    if caster.mana < ability.mana_cost {
+        return Err(format!("Not enough mana for {}", ability.name));
+    }
+
+    let ready_at =
+        ctx.timestamp + TimeDuration::from_duration(Duration::from_millis(ability.cooldown_ms));
+    match cooldown {
+        Some(cooldown) => {
+            ctx.db.abilities_cooldowns().id().update(AbilityCooldown {
+                ready_at,
+                ..cooldown
+            });
+        }
+        None => {
+            ctx.db.abilities_cooldowns().insert(AbilityCooldown {
+                id: 0,
+                player_id: character_id,
+                ability_id,
+                ready_at,
+            });
+        }
+    }
+
+    let damage = ability.damage + caster.bonus_damage;
+    caster.mana -= ability.mana_cost;
+    caster.last_combat = ctx.timestamp;
+    ctx.db.players().id().update(caster);
+
+    Ok(damage)
+}
+
+// Whether the player can act, dead players have to wait for their respawn
+pub fn ensure_alive(ctx: &ReducerContext, player_id: u64) -> Result<(), String> {
+    alive_player(ctx, player_id).map(|_| ())
+}
+
+fn alive_player(ctx: &ReducerContext, player_id: u64) -> Result<Player, String> {
+    let player = ctx
+        .db
+        .players()
+        .id()
+        .find(player_id)
+        .ok_or(format!("Player {} not found", player_id))?;
+    if player.dead {
+        return Err(format!("Player {} is dead", player_id));
+    }
+    Ok(player)
+}
+
+// Take health from the player, killing it once none is left. The caller saves the row
+pub fn damage_player(
+    ctx: &ReducerContext,
+    victim: &mut Player,
+    damage: f32,
+    attacker: Option<u64>,
+) {
+    victim.last_combat = ctx.timestamp;
+    victim.health = (victim.health - damage).max(0.0);
+    if victim.health <= 0.0 {
+        kill_player(ctx, victim, attacker);
+    }
+}
+
+// Mark the player dead, drop the moves it still had queued and schedule its respawn
+fn kill_player(ctx: &ReducerContext, player: &mut Player, killer: Option<u64>) {
+    log::info!("Player {} died", player.id);
+    player.dead = true;
+    ctx.db.players_inputs().player_id().delete(player.id);
+    drop_gold(ctx, player.id, killer);
+
+    let delay = ServerConfig::get(ctx).respawn_delay_ms;
+    ctx.db.players_respawns().insert(PlayerRespawn {
+        id: 0,
+        player_id: player.id,
+        scheduled_at: ScheduleAt::Time(
+            ctx.timestamp + TimeDuration::from_duration(Duration::from_millis(delay)),
+        ),
+    });
+}
+
+// Players lose part of their gold when they die, reserved to the player who killed them
+fn drop_gold(ctx: &ReducerContext, player_id: u64, killer: Option<u64>) {
+    let Some(position) = ctx.db.players_positions().id().find(player_id) else {
+        return;
+    };
+    let stacks: Vec<InventorySlot> = ctx
+        .db
+        .inventory_slots()
+        .owner()
+        .filter(&player_id)
+        .filter(|slot| slot.item_id == ItemDefinition::GOLD_COIN)
+        .collect();
+    let gold = stacks.iter().map(|stack| stack.quantity).sum::<u32>();
+    let dropped = gold * DEATH_GOLD_DROP_PERCENT / 100;
+    if dropped == 0 {
+        return;
+    }
+
+    let mut left = dropped;
+    for stack in stacks {
+        let taken = left.min(stack.quantity);
+        remove_quantity(ctx, stack, taken);
+        left -= taken;
+        if left == 0 {
+            break;
+        }
+    }
+
+    spawn_world_item(
+        ctx,
+        ItemDefinition::GOLD_COIN,
+        dropped,
+        (position.x, position.y - GROUND_HEIGHT, position.z),
+        killer,
+    );
+}
@@ -0,0 +1,343 @@
+use std::{collections::HashSet, fmt, time::Duration};
+
+use spacetimedb::{log_stopwatch::LogStopwatch, reducer, ReducerContext, Table};
+
+use crate::{
+    admin::ensure_scheduler,
+    characters::clear_hot_data,
+    players::{delete_window, insert_hot_data},
+    tables::{
+        accounts::sessions,
+        config::{LodRing, ServerConfig},
+        players::players,
+        players_positions::{
+            players_positions, players_positions_lods, ConsistencyCheck, PlayerPosition,
+        },
+        players_windows::{players_windows, players_windows_cells},
+    },
+};
+
+// Interval between two consistency checks
+pub const CONSISTENCY_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+// Ids of the rows the hot data checks look at, gathered so that the checks don't need a database
+#[derive(Default)]
+struct HotData {
+    // Players with a position
+    positions: Vec<u64>,
+    // Players with a window
+    windows: Vec<u64>,
+    // Id and player of the windows cells
+    cells: Vec<(u64, u64)>,
+    // Id and player of the positions snapshots
+    snapshots: Vec<(u64, u64)>,
+    // Players marked online
+    online: Vec<u64>,
+    // Characters someone is playing
+    sessions: Vec<u64>,
+}
+
+// Inconsistencies found between the players and their hot data, all of them are fixed on the spot
+#[derive(Default, Debug, PartialEq)]
+struct ConsistencyReport {
+    // Windows without a position, deleted along with their cells
+    orphaned_windows: Vec<u64>,
+    orphaned_cells: Vec<u64>,
+    orphaned_snapshots: Vec<u64>,
+    // Players in a session without a position, put back where they were last saved
+    missing_positions: Vec<u64>,
+    // Positions of players nobody is playing anymore, deleted along with the rest of their hot data
+    sessionless_positions: Vec<u64>,
+    // Players nobody is playing anymore, marked offline
+    stale_online: Vec<u64>,
+}
+
+impl ConsistencyReport {
+    fn is_clean(&self) -> bool {
+        self.orphaned_windows.is_empty()
+            && self.orphaned_cells.is_empty()
+            && self.orphaned_snapshots.is_empty()
+            && self.missing_positions.is_empty()
+            && self.sessionless_positions.is_empty()
+            && self.stale_online.is_empty()
+    }
+}
+
+impl fmt::Display for ConsistencyReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} orphaned windows, {} orphaned cells, {} orphaned snapshots, {} missing positions, \
+            {} positions without a session, {} online without a session",
+            self.orphaned_windows.len(),
+            self.orphaned_cells.len(),
+            self.orphaned_snapshots.len(),
+            self.missing_positions.len(),
+            self.sessionless_positions.len(),
+            self.stale_online.len()
+        )
+    }
+}
+
+#[reducer]
+pub fn report_consistency(ctx: &ReducerContext, _row: ConsistencyCheck) -> Result<(), String> {
+    ensure_scheduler(ctx)?;
+    let _stopwatch = LogStopwatch::new("report_consistency");
+
+    let report = check_consistency(ctx);
+    if report.is_clean() {
+        log::info!("report_consistency: {}", report);
+    } else {
+        log::warn!("report_consistency: {}", report);
+    }
+
+    Ok(())
+}
+
+// Delete the hot rows that lost their player and recreate the ones of online players
+fn check_consistency(ctx: &ReducerContext) -> ConsistencyReport {
+    let data = HotData {
+        positions: ctx
+            .db
+            .players_positions()
+            .iter()
+            .map(|position| position.id)
+            .collect(),
+        windows: ctx
+            .db
+            .players_windows()
+            .iter()
+            .map(|window| window.id)
+            .collect(),
+        cells: ctx
+            .db
+            .players_windows_cells()
+            .iter()
+            .map(|cell| (cell.id, cell.player_id))
+            .collect(),
+        snapshots: ctx
+            .db
+            .players_positions_lods()
+            .iter()
+            .map(|snapshot| (snapshot.id, snapshot.player_id))
+            .collect(),
+        online: ctx
+            .db
+            .players()
+            .online()
+            .filter(&true)
+            .map(|player| player.id)
+            .collect(),
+        sessions: ctx
+            .db
+            .sessions()
+            .iter()
+            .map(|session| session.character_id)
+            .collect(),
+    };
+    let report = find_inconsistencies(&data);
+
+    for id in &report.orphaned_windows {
+        if let Some(window) = ctx.db.players_windows().id().find(id) {
+            delete_window(ctx, &window);
+        }
+    }
+    for id in &report.orphaned_cells {
+        ctx.db.players_windows_cells().id().delete(id);
+    }
+    for id in &report.orphaned_snapshots {
+        ctx.db.players_positions_lods().id().delete(id);
+    }
+
+    // Hot data left without a session can't be trusted, the player starts over from cold storage
+    for id in &report.sessionless_positions {
+        clear_hot_data(ctx, *id);
+    }
+    for id in &report.stale_online {
+        if let Some(mut player) = ctx.db.players().id().find(id) {
+            player.online = false;
+            ctx.db.players().id().update(player);
+        }
+    }
+
+    let config = ServerConfig::get(ctx);
+    let rings = LodRing::all(ctx);
+    for id in &report.missing_positions {
+        let (Some(player), Some(session)) = (
+            ctx.db.players().id().find(id),
+            ctx.db.sessions().character_id().find(id),
+        ) else {
+            continue;
+        };
+
+        // Start over from the last saved position
+        let position = PlayerPosition::from_player(&player, ctx.timestamp);
+        insert_hot_data(
+            ctx,
+            position,
+            session.identity,
+            config.default_view_distance,
+            &config,
+            &rings,
+        );
+    }
+
+    report
+}
+
+// Compare the hot data with the players, without fixing anything
+fn find_inconsistencies(data: &HotData) -> ConsistencyReport {
+    let positions: HashSet<u64> = data.positions.iter().copied().collect();
+    let windows: HashSet<u64> = data.windows.iter().copied().collect();
+    let sessions: HashSet<u64> = data.sessions.iter().copied().collect();
+
+    ConsistencyReport {
+        orphaned_windows: data
+            .windows
+            .iter()
+            .filter(|id| !positions.contains(*id))
+            .copied()
+            .collect(),
+        // The cells of orphaned windows go away with their window
+        orphaned_cells: data
+            .cells
+            .iter()
+            .filter(|(_, player_id)| !windows.contains(player_id))
+            .map(|(id, _)| *id)
+            .collect(),
+        orphaned_snapshots: data
+            .snapshots
+            .iter()
+            .filter(|(_, player_id)| !positions.contains(player_id))
+            .map(|(id, _)| *id)
+            .collect(),
+        missing_positions: data
+            .online
+            .iter()
+            .filter(|id| sessions.contains(*id) && !positions.contains(*id))
+            .copied()
+            .collect(),
+        sessionless_positions: data
+            .positions
+            .iter()
+            .filter(|id| !sessions.contains(*id))
+            .copied()
+            .collect(),
+        stale_online: data
+            .online
+            .iter()
+            .filter(|id| !sessions.contains(*id))
+            .copied()
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Two players in the world with their window, one cell and one snapshot each
+    fn consistent() -> HotData {
+        HotData {
+            positions: vec![1, 2],
+            windows: vec![1, 2],
+            cells: vec![(10, 1), (20, 2)],
+            snapshots: vec![(100, 1), (200, 2)],
+            online: vec![1, 2],
+            sessions: vec![1, 2],
+        }
+    }
+
+    #[test]
+    fn consistent_data_is_clean() {
+        let report = find_inconsistencies(&consistent());
+
+        assert!(report.is_clean());
+        assert_eq!(report, ConsistencyReport::default());
+    }
+
+    #[test]
+    fn finds_windows_without_a_position() {
+        let mut data = consistent();
+        data.windows.push(3);
+        data.cells.push((30, 3));
+
+        let report = find_inconsistencies(&data);
+        assert_eq!(report.orphaned_windows, vec![3]);
+        assert!(report.orphaned_cells.is_empty());
+        assert!(!report.is_clean());
+    }
+
+    #[test]
+    fn finds_cells_without_a_window() {
+        let mut data = consistent();
+        data.cells.push((30, 3));
+
+        let report = find_inconsistencies(&data);
+        assert_eq!(report.orphaned_cells, vec![30]);
+        assert!(report.orphaned_windows.is_empty());
+    }
+
+    #[test]
+    fn finds_snapshots_without_a_position() {
+        let mut data = consistent();
+        data.snapshots.push((300, 3));
+
+        let report = find_inconsistencies(&data);
+        assert_eq!(report.orphaned_snapshots, vec![300]);
+    }
+
+    #[test]
+    fn finds_players_in_a_session_without_a_position() {
+        let mut data = consistent();
+        data.positions.retain(|id| *id != 2);
+        data.windows.retain(|id| *id != 2);
+        data.cells.retain(|(_, player_id)| *player_id != 2);
+        data.snapshots.retain(|(_, player_id)| *player_id != 2);
+
+        let report = find_inconsistencies(&data);
+        assert_eq!(report.missing_positions, vec![2]);
+        assert!(report.stale_online.is_empty());
+    }
+
+    #[test]
+    fn finds_online_players_without_a_session() {
+        let mut data = consistent();
+        data.online.push(3);
+
+        let report = find_inconsistencies(&data);
+        assert_eq!(report.stale_online, vec![3]);
+        assert!(report.missing_positions.is_empty());
+        assert!(report.sessionless_positions.is_empty());
+    }
+
+    #[test]
+    fn finds_positions_without_a_session() {
+        let mut data = consistent();
+        data.sessions.retain(|id| *id != 2);
+
+        let report = find_inconsistencies(&data);
+        assert_eq!(report.sessionless_positions, vec![2]);
+        assert_eq!(report.stale_online, vec![2]);
+        assert!(report.missing_positions.is_empty());
+        assert!(report.orphaned_windows.is_empty());
+    }
+
+    #[test]
+    fn reports_every_inconsistency_at_once() {
+        let mut data = consistent();
+        data.positions.retain(|id| *id != 1);
+        data.online.push(3);
+
+        let report = find_inconsistencies(&data);
+        assert_eq!(report.orphaned_windows, vec![1]);
+        assert_eq!(report.orphaned_snapshots, vec![100]);
+        assert_eq!(report.missing_positions, vec![1]);
+        assert_eq!(report.stale_online, vec![3]);
+        assert_eq!(
+            report.to_string(),
+            "1 orphaned windows, 0 orphaned cells, 1 orphaned snapshots, 1 missing positions, \
+            0 positions without a session, 1 online without a session"
+        );
+    }
+}
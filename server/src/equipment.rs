@@ -0,0 +1,101 @@
+use spacetimedb::{reducer, ReducerContext, Table};
+
+use crate::{
+    characters::current_character,
+    inventory::{give_item, item_definition, remove_quantity, slot_content},
+    tables::{
+        items::{equipped_items, EquipmentSlot, EquippedItem},
+        players::{players, Player, BASE_MAX_HEALTH, BASE_MAX_MANA},
+        players_positions::players_positions,
+    },
+};
+
+// Wear the item of an inventory slot, the item worn in the same equipment slot goes back to the inventory
+#[reducer]
+fn equip_item(ctx: &ReducerContext, inventory_slot: u32) -> Result<(), String> {
+    let owner = current_character(ctx)?;
+    let content = slot_content(ctx, owner, inventory_slot).ok_or("Nothing to equip")?;
+    let definition = item_definition(ctx, content.item_id)?;
+    let slot = definition
+        .equipment_slot
+        .ok_or(format!("{} can't be equipped", definition.name))?;
+
+    // Taken out first so that the slot is free for the item it replaces
+    remove_quantity(ctx, content, 1);
+    if let Some(worn) = equipped_in(ctx, owner, slot) {
+        ctx.db.equipped_items().id().delete(worn.id);
+        give_item(ctx, owner, worn.item_id, 1)?;
+    }
+    ctx.db.equipped_items().insert(EquippedItem {
+        id: 0,
+        owner,
+        slot,
+        item_id: content.item_id,
+    });
+
+    update_stats(ctx, owner)
+}
+
+#[reducer]
+fn unequip_item(ctx: &ReducerContext, slot: EquipmentSlot) -> Result<(), String> {
+    let owner = current_character(ctx)?;
+    let worn = equipped_in(ctx, owner, slot).ok_or(format!("Nothing worn as {:?}", slot))?;
+
+    ctx.db.equipped_items().id().delete(worn.id);
+    give_item(ctx, owner, worn.item_id, 1)?;
+
+    update_stats(ctx, owner)
+}
+
+// Recompute the stats derived from the equipment of a character
+fn recompute_stats(ctx: &ReducerContext, player: &mut Player) {
+    let mut max_health = BASE_MAX_HEALTH;
+    let mut max_mana = BASE_MAX_MANA;
+    let mut speed_multiplier = 1.0;
+    let mut bonus_damage = 0.0;
+
+    for worn in ctx.db.equipped_items().owner().filter(&player.id) {
+        let Ok(definition) = item_definition(ctx, worn.item_id) else {
+            log::warn!("Player {} wears unknown item {}", player.id, worn.item_id);
+            continue;
+        };
+        max_health += definition.bonus_health;
+        max_mana += definition.bonus_mana;
+        speed_multiplier += definition.bonus_speed;
+        bonus_damage += definition.bonus_damage;
+    }
+
+    player.max_health = max_health;
+    player.health = player.health.min(max_health);
+    player.max_mana = max_mana;
+    player.mana = player.mana.min(max_mana);
+    player.speed_multiplier = speed_multiplier;
+    player.bonus_damage = bonus_damage;
+}
+
+fn update_stats(ctx: &ReducerContext, player_id: u64) -> Result<(), String> {
+    let mut player = ctx
+        .db
+        .players()
+        .id()
+        .find(player_id)
+        .ok_or(format!("Player {} not found", player_id))?;
+    recompute_stats(ctx, &mut player);
+
+    // The simulation reads the speed from the hot position
+    if let Some(mut position) = ctx.db.players_positions().id().find(player_id) {
+        position.speed_multiplier = player.speed_multiplier;
+        ctx.db.players_positions().id().update(position);
+    }
+
+    ctx.db.players().id().update(player);
+    Ok(())
+}
+
+fn equipped_in(ctx: &ReducerContext, owner: u64, slot: EquipmentSlot) -> Option<EquippedItem> {
+    ctx.db
+        .equipped_items()
+        .owner()
+        .filter(&owner)
+        .find(|worn| worn.slot == slot)
+}
@@ -4,28 +4,119 @@ use spacetimedb::{client_visibility_filter, Filter};
 const ONLINE_PLAYERS: Filter = Filter::Sql("SELECT * FROM players WHERE online = true");
 
 #[client_visibility_filter]
-// Only show players within the high-resolution window of the sender on players_positions
-const PLAYERS_POSITIONS_FILTER: Filter = Filter::Sql(
-    "SELECT p.* FROM players_positions p
-        JOIN players_windows pw
-        WHERE pw.id = :sender
-            AND p.x >= pw.hr_bl_x AND p.x <= pw.hr_tr_x
-            AND p.z >= pw.hr_bl_z AND p.z <= pw.hr_tr_z
-        ",
-);
-
-#[client_visibility_filter]
-// Only show players in the LR window but outside the HR window
-const PLAYERS_POSITIONS_LR_FILTER: Filter = Filter::Sql(
-    "SELECT p.* FROM players_positions_lr p
-        JOIN players_windows pw
-        WHERE
-            pw.id = :sender
-            AND p.x >= pw.lr_bl_x AND p.x <= pw.lr_tr_x
-            AND p.z >= pw.lr_bl_z AND p.z <= pw.lr_tr_z
-            AND (
-                p.x < pw.hr_bl_x OR p.x > pw.hr_tr_x OR
-                p.z < pw.hr_bl_z OR p.z > pw.hr_tr_z
-        )
+// Characters are only listed to the account owning them
+const CHARACTERS_FILTER: Filter = Filter::Sql("SELECT * FROM characters WHERE account = :sender");
+
+#[client_visibility_filter]
+const SESSIONS_FILTER: Filter = Filter::Sql("SELECT * FROM sessions WHERE identity = :sender");
+
+#[client_visibility_filter]
+// Only send movement corrections to the player they are about
+const MOVEMENT_VIOLATIONS_FILTER: Filter = Filter::Sql(
+    "SELECT v.* FROM movement_violations v
+        JOIN sessions s ON v.player_id = s.character_id
+        WHERE s.identity = :sender
+    ",
+);
+
+#[client_visibility_filter]
+// Acknowledged inputs are only needed by the player who sent them
+const PLAYERS_INPUTS_ACKS_FILTER: Filter = Filter::Sql(
+    "SELECT a.* FROM players_inputs_acks a
+        JOIN sessions s ON a.player_id = s.character_id
+        WHERE s.identity = :sender
+    ",
+);
+
+#[client_visibility_filter]
+// Cooldowns only matter to the player casting the abilities
+const ABILITIES_COOLDOWNS_FILTER: Filter = Filter::Sql(
+    "SELECT a.* FROM abilities_cooldowns a
+        JOIN sessions s ON a.player_id = s.character_id
+        WHERE s.identity = :sender
+    ",
+);
+
+#[client_visibility_filter]
+// Players only see their own inventory
+const INVENTORY_SLOTS_FILTER: Filter = Filter::Sql(
+    "SELECT i.* FROM inventory_slots i
+        JOIN sessions s ON i.owner = s.character_id
+        WHERE s.identity = :sender
+    ",
+);
+
+#[client_visibility_filter]
+// Gear is shown on the characters in the world
+const EQUIPPED_ITEMS_FILTER: Filter = Filter::Sql(
+    "SELECT e.* FROM equipped_items e
+        JOIN players p ON e.owner = p.id
+        WHERE p.online = true
+    ",
+);
+
+#[client_visibility_filter]
+// Windows give away where their player stands, clients only see their own
+const PLAYERS_WINDOWS_FILTER: Filter =
+    Filter::Sql("SELECT * FROM players_windows WHERE identity = :sender");
+
+#[client_visibility_filter]
+const PLAYERS_WINDOWS_CELLS_FILTER: Filter =
+    Filter::Sql("SELECT * FROM players_windows_cells WHERE identity = :sender");
+
+#[client_visibility_filter]
+// Only show the position snapshots of the ring matching the cell of the sender's window they are in,
+// cells of a window never belong to more than one ring
+const PLAYERS_POSITIONS_LODS_FILTER: Filter = Filter::Sql(
+    "SELECT p.* FROM players_positions_lods p
+        JOIN players_windows_cells c ON p.cell = c.cell
+        WHERE c.identity = :sender AND p.ring = c.ring
+    ",
+);
+
+#[client_visibility_filter]
+// NPC stats are sent along with their snapshots, to the windows covering their cell
+const NPCS_FILTER: Filter = Filter::Sql(
+    "SELECT n.* FROM npcs n
+        JOIN players_windows_cells c ON n.cell = c.cell
+        WHERE c.identity = :sender
     ",
 );
+
+#[client_visibility_filter]
+// Same rings as the players snapshots
+const NPCS_POSITIONS_LODS_FILTER: Filter = Filter::Sql(
+    "SELECT n.* FROM npcs_positions_lods n
+        JOIN players_windows_cells c ON n.cell = c.cell
+        WHERE c.identity = :sender AND n.ring = c.ring
+    ",
+);
+
+#[client_visibility_filter]
+// Items on the ground are only sent once they lie in the innermost ring of the sender's window
+const WORLD_ITEMS_FILTER: Filter = Filter::Sql(
+    "SELECT i.* FROM world_items i
+        JOIN players_windows_cells c ON i.cell = c.cell
+        WHERE c.identity = :sender AND c.ring = 0
+    ",
+);
+
+#[client_visibility_filter]
+const CHAT_GLOBAL_FILTER: Filter = Filter::Sql("SELECT * FROM chat_messages WHERE global = true");
+
+#[client_visibility_filter]
+// Local messages are heard by the players whose window covers the cell they were sent from
+const CHAT_LOCAL_FILTER: Filter = Filter::Sql(
+    "SELECT m.* FROM chat_messages m
+        JOIN players_windows_cells c ON m.cell = c.cell
+        WHERE c.identity = :sender AND m.local = true
+    ",
+);
+
+#[client_visibility_filter]
+// Whispers are only seen by their recipient, senders always see their own messages
+const CHAT_WHISPERS_FILTER: Filter =
+    Filter::Sql("SELECT * FROM chat_messages WHERE recipient = :sender");
+
+#[client_visibility_filter]
+const CHAT_SENT_FILTER: Filter = Filter::Sql("SELECT * FROM chat_messages WHERE sender = :sender");
@@ -0,0 +1,24 @@
+// Size of a grid cell in world units, positions are bucketed into cells so that
+// visibility can be resolved with an indexed equality join instead of range predicates
+pub const CELL_SIZE: f32 = 16.0;
+
+// Pack the cell coordinates containing (x, z) into a single indexable value
+pub fn cell_at(x: f32, z: f32) -> u64 {
+    pack_cell(
+        (x / CELL_SIZE).floor() as i32,
+        (z / CELL_SIZE).floor() as i32,
+    )
+}
+
+pub fn pack_cell(cell_x: i32, cell_z: i32) -> u64 {
+    ((cell_x as u32 as u64) << 32) | cell_z as u32 as u64
+}
+
+pub fn unpack_cell(cell: u64) -> (i32, i32) {
+    ((cell >> 32) as u32 as i32, cell as u32 as i32)
+}
+
+// Number of cells needed around the center cell to cover a window of the given size
+pub fn cell_radius(size: f32) -> i32 {
+    (size / 2.0 / CELL_SIZE).ceil() as i32
+}
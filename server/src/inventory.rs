@@ -0,0 +1,290 @@
+use spacetimedb::{reducer, ReducerContext, Table};
+
+use crate::{
+    characters::current_character,
+    movement::GROUND_HEIGHT,
+    tables::{
+        items::{inventory_slots, item_definitions, InventorySlot, ItemDefinition},
+        players_positions::players_positions,
+    },
+    world_items::spawn_world_item,
+};
+
+// Number of slots of every inventory
+pub const INVENTORY_SIZE: u32 = 24;
+
+// Moving a stack onto a stack of the same item merges them as far as the stack limit allows,
+// onto another item the two stacks are swapped
+#[reducer]
+fn move_item(ctx: &ReducerContext, from_slot: u32, to_slot: u32) -> Result<(), String> {
+    let owner = current_character(ctx)?;
+    check_slot(to_slot)?;
+    if from_slot == to_slot {
+        return Ok(());
+    }
+
+    let mut from = slot_content(ctx, owner, from_slot).ok_or("Nothing to move")?;
+    let Some(mut to) = slot_content(ctx, owner, to_slot) else {
+        from.slot = to_slot;
+        ctx.db.inventory_slots().id().update(from);
+        return Ok(());
+    };
+
+    if from.item_id != to.item_id {
+        from.slot = to_slot;
+        to.slot = from_slot;
+        ctx.db.inventory_slots().id().update(from);
+        ctx.db.inventory_slots().id().update(to);
+        return Ok(());
+    }
+
+    let definition = item_definition(ctx, to.item_id)?;
+    let moved = from
+        .quantity
+        .min(definition.max_stack.saturating_sub(to.quantity));
+    if moved == 0 {
+        return Err(format!("The stack of {} is full", definition.name));
+    }
+
+    to.quantity += moved;
+    ctx.db.inventory_slots().id().update(to);
+    remove_quantity(ctx, from, moved);
+    Ok(())
+}
+
+#[reducer]
+fn split_stack(
+    ctx: &ReducerContext,
+    from_slot: u32,
+    to_slot: u32,
+    quantity: u32,
+) -> Result<(), String> {
+    let owner = current_character(ctx)?;
+    check_slot(to_slot)?;
+
+    let mut from = slot_content(ctx, owner, from_slot).ok_or("Nothing to split")?;
+    if quantity == 0 || quantity >= from.quantity {
+        return Err(format!(
+            "Can't split {} items out of a stack of {}",
+            quantity, from.quantity
+        ));
+    }
+    if slot_content(ctx, owner, to_slot).is_some() {
+        return Err(format!("Slot {} is not empty", to_slot));
+    }
+
+    from.quantity -= quantity;
+    ctx.db.inventory_slots().id().update(from);
+    ctx.db.inventory_slots().insert(InventorySlot {
+        id: 0,
+        owner,
+        slot: to_slot,
+        item_id: from.item_id,
+        quantity,
+    });
+    Ok(())
+}
+
+// Drop items of a stack on the ground at the feet of the character
+#[reducer]
+fn drop_item(ctx: &ReducerContext, slot: u32, quantity: u32) -> Result<(), String> {
+    let owner = current_character(ctx)?;
+    let content = slot_content(ctx, owner, slot).ok_or("Nothing to drop")?;
+    if quantity == 0 || quantity > content.quantity {
+        return Err(format!(
+            "Can't drop {} items out of a stack of {}",
+            quantity, content.quantity
+        ));
+    }
+    let position = ctx
+        .db
+        .players_positions()
+        .id()
+        .find(owner)
+        .ok_or("Not in the world")?;
+
+    remove_quantity(ctx, content, quantity);
+    spawn_world_item(
+        ctx,
+        content.item_id,
+        quantity,
+        (position.x, position.y - GROUND_HEIGHT, position.z),
+        None,
+    );
+    Ok(())
+}
+
+// Add items to the inventory, topping up the existing stacks before filling empty slots.
+// Nothing is added when they don't all fit
+pub fn give_item(
+    ctx: &ReducerContext,
+    owner: u64,
+    item_id: u32,
+    quantity: u32,
+) -> Result<(), String> {
+    let definition = item_definition(ctx, item_id)?;
+    let slots: Vec<InventorySlot> = ctx.db.inventory_slots().owner().filter(&owner).collect();
+
+    if room_for(&definition, &slots) < quantity {
+        return Err(format!(
+            "Not enough room for {} {}",
+            quantity, definition.name
+        ));
+    }
+
+    let mut left = quantity;
+    for mut stack in open_stacks(&definition, &slots) {
+        let added = left.min(definition.max_stack - stack.quantity);
+        stack.quantity += added;
+        ctx.db.inventory_slots().id().update(stack);
+        left -= added;
+        if left == 0 {
+            return Ok(());
+        }
+    }
+    for slot in empty_slots(&slots) {
+        let added = left.min(definition.max_stack);
+        ctx.db.inventory_slots().insert(InventorySlot {
+            id: 0,
+            owner,
+            slot,
+            item_id,
+            quantity: added,
+        });
+        left -= added;
+        if left == 0 {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+// Stacks of the item that are not full yet
+fn open_stacks(definition: &ItemDefinition, slots: &[InventorySlot]) -> Vec<InventorySlot> {
+    slots
+        .iter()
+        .filter(|slot| slot.item_id == definition.id && slot.quantity < definition.max_stack)
+        .copied()
+        .collect()
+}
+
+fn empty_slots(slots: &[InventorySlot]) -> Vec<u32> {
+    (0..INVENTORY_SIZE)
+        .filter(|index| !slots.iter().any(|slot| slot.slot == *index))
+        .collect()
+}
+
+// Number of items that still fit in the inventory, topping up the open stacks and filling the
+// empty slots
+fn room_for(definition: &ItemDefinition, slots: &[InventorySlot]) -> u32 {
+    open_stacks(definition, slots)
+        .iter()
+        .map(|stack| definition.max_stack - stack.quantity)
+        .sum::<u32>()
+        + empty_slots(slots).len() as u32 * definition.max_stack
+}
+
+pub fn item_definition(ctx: &ReducerContext, item_id: u32) -> Result<ItemDefinition, String> {
+    ctx.db
+        .item_definitions()
+        .id()
+        .find(item_id)
+        .ok_or(format!("Item {} not found", item_id))
+}
+
+pub fn slot_content(ctx: &ReducerContext, owner: u64, slot: u32) -> Option<InventorySlot> {
+    ctx.db
+        .inventory_slots()
+        .owner()
+        .filter(&owner)
+        .find(|content| content.slot == slot)
+}
+
+// Take items out of a stack, deleting it once empty
+pub fn remove_quantity(ctx: &ReducerContext, mut content: InventorySlot, quantity: u32) {
+    if quantity >= content.quantity {
+        ctx.db.inventory_slots().id().delete(content.id);
+        return;
+    }
+
+    content.quantity -= quantity;
+    ctx.db.inventory_slots().id().update(content);
+}
+
+fn check_slot(slot: u32) -> Result<(), String> {
+    if slot >= INVENTORY_SIZE {
+        return Err(format!("Invalid inventory slot {}", slot));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn definition(item_id: u32) -> ItemDefinition {
+        ItemDefinition::defaults()
+            .into_iter()
+            .find(|definition| definition.id == item_id)
+            .unwrap()
+    }
+
+    fn stack(slot: u32, item_id: u32, quantity: u32) -> InventorySlot {
+        InventorySlot {
+            id: slot as u64 + 1,
+            owner: 1,
+            slot,
+            item_id,
+            quantity,
+        }
+    }
+
+    #[test]
+    fn empty_inventories_fill_every_slot() {
+        let potion = definition(ItemDefinition::HEALTH_POTION);
+        assert_eq!(room_for(&potion, &[]), INVENTORY_SIZE * potion.max_stack);
+    }
+
+    #[test]
+    fn open_stacks_are_topped_up() {
+        let potion = definition(ItemDefinition::HEALTH_POTION);
+        let slots = [
+            stack(0, ItemDefinition::HEALTH_POTION, potion.max_stack - 5),
+            stack(1, ItemDefinition::HEALTH_POTION, potion.max_stack),
+            stack(2, ItemDefinition::MANA_POTION, 1),
+        ];
+
+        assert_eq!(open_stacks(&potion, &slots).len(), 1);
+        assert_eq!(
+            room_for(&potion, &slots),
+            5 + (INVENTORY_SIZE - 3) * potion.max_stack
+        );
+    }
+
+    #[test]
+    fn full_inventories_only_take_open_stacks() {
+        let potion = definition(ItemDefinition::HEALTH_POTION);
+        let mut slots: Vec<InventorySlot> = (0..INVENTORY_SIZE)
+            .map(|slot| stack(slot, ItemDefinition::MANA_POTION, 1))
+            .collect();
+        assert!(empty_slots(&slots).is_empty());
+        assert_eq!(room_for(&potion, &slots), 0);
+
+        slots[3] = stack(3, ItemDefinition::HEALTH_POTION, potion.max_stack - 1);
+        assert_eq!(room_for(&potion, &slots), 1);
+    }
+
+    #[test]
+    fn unstackable_items_take_a_slot_each() {
+        let gold = definition(ItemDefinition::GOLD_COIN);
+        let slots = [stack(0, 3, 1), stack(5, 4, 1)];
+
+        assert_eq!(empty_slots(&slots).len(), INVENTORY_SIZE as usize - 2);
+        assert_eq!(room_for(&definition(3), &slots), INVENTORY_SIZE - 2);
+        assert_eq!(
+            room_for(&gold, &slots),
+            (INVENTORY_SIZE - 2) * gold.max_stack
+        );
+    }
+}
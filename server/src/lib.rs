@@ -1,4 +1,16 @@
+mod admin;
+mod characters;
+mod chat;
+mod combat;
+mod consistency;
+mod equipment;
 mod filters;
+mod grid;
+mod inventory;
 mod lifecycle;
+mod movement;
+mod npcs;
 mod players;
+mod spawn_regions;
 mod tables;
+mod world_items;
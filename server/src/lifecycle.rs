@@ -2,50 +2,135 @@ use std::time::Duration;
 
 use spacetimedb::{reducer, ReducerContext, ScheduleAt, Table, TimeDuration};
 
-use crate::tables::{
-    players::{players, Player},
-    players_positions::{players_positions, players_positions_lr, PlayerPosition},
-    players_windows::{players_window_updates, players_windows, PlayerWindow, PlayerWindowUpdate},
+use crate::{
+    characters::end_session,
+    combat::REGEN_INTERVAL,
+    consistency::CONSISTENCY_CHECK_INTERVAL,
+    npcs::NPC_TICK_INTERVAL,
+    players::schedule_autosave,
+    spawn_regions::SPAWN_REGIONS_INTERVAL,
+    tables::{
+        abilities::{abilities, Ability},
+        accounts::{accounts, admins, sessions, Account, Admin},
+        chat::{chat_cleanups, ChatCleanup},
+        config::{lod_rings, server_config, LodRing, ServerConfig},
+        items::{item_definitions, world_items_despawns, ItemDefinition, WorldItemDespawn},
+        npcs::{
+            npc_types, npcs_ai_ticks, spawn_regions_ticks, NpcAiTick, NpcType, SpawnRegionTick,
+        },
+        players::{players_regen_ticks, PlayerRegenTick},
+        players_inputs::{players_simulation_ticks, PlayerSimulationTick},
+        players_positions::{
+            consistency_checks, players_lods_flushes, ConsistencyCheck, PlayerPositionLodFlush,
+        },
+        players_windows::{players_window_updates, PlayerWindowUpdate},
+    },
 };
 
 #[reducer(init)]
 fn on_init(ctx: &ReducerContext) {
+    let config = ctx.db.server_config().insert(ServerConfig::default());
+    for ring in LodRing::defaults() {
+        ctx.db.lod_rings().insert(ring);
+    }
+    for ability in Ability::defaults() {
+        ctx.db.abilities().insert(ability);
+    }
+    for item in ItemDefinition::defaults() {
+        ctx.db.item_definitions().insert(item);
+    }
+    for npc_type in NpcType::defaults() {
+        ctx.db.npc_types().insert(npc_type);
+    }
+    // The module owner publishes it, other admins are added by them
+    ctx.db.admins().insert(Admin {
+        identity: ctx.sender,
+        added_at: ctx.timestamp,
+    });
+
     ctx.db.players_window_updates().insert(PlayerWindowUpdate {
         id: 1,
         scheduled_at: ScheduleAt::Interval(TimeDuration::from_duration(Duration::from_millis(250))),
     });
+
+    let flush_interval = TimeDuration::from_duration(Duration::from_millis(500));
+    ctx.db
+        .players_lods_flushes()
+        .insert(PlayerPositionLodFlush {
+            id: 1,
+            scheduled_at: ScheduleAt::Interval(flush_interval),
+        });
+
+    let simulation_interval = TimeDuration::from_duration(Duration::from_millis(50));
+    ctx.db
+        .players_simulation_ticks()
+        .insert(PlayerSimulationTick {
+            id: 1,
+            scheduled_at: ScheduleAt::Interval(simulation_interval),
+        });
+
+    ctx.db.players_regen_ticks().insert(PlayerRegenTick {
+        id: 1,
+        scheduled_at: ScheduleAt::Interval(TimeDuration::from_duration(REGEN_INTERVAL)),
+    });
+
+    ctx.db.chat_cleanups().insert(ChatCleanup {
+        id: 1,
+        scheduled_at: ScheduleAt::Interval(TimeDuration::from_duration(Duration::from_secs(60))),
+    });
+
+    ctx.db.npcs_ai_ticks().insert(NpcAiTick {
+        id: 1,
+        scheduled_at: ScheduleAt::Interval(TimeDuration::from_duration(NPC_TICK_INTERVAL)),
+    });
+
+    ctx.db.spawn_regions_ticks().insert(SpawnRegionTick {
+        id: 1,
+        scheduled_at: ScheduleAt::Interval(TimeDuration::from_duration(SPAWN_REGIONS_INTERVAL)),
+    });
+
+    ctx.db.world_items_despawns().insert(WorldItemDespawn {
+        id: 1,
+        scheduled_at: ScheduleAt::Interval(TimeDuration::from_duration(Duration::from_secs(5))),
+    });
+
+    ctx.db.consistency_checks().insert(ConsistencyCheck {
+        id: 1,
+        scheduled_at: ScheduleAt::Interval(TimeDuration::from_duration(CONSISTENCY_CHECK_INTERVAL)),
+    });
+
+    schedule_autosave(ctx, &config);
 }
 
 #[reducer(client_connected)]
 fn on_connected(ctx: &ReducerContext) {
-    ctx.db.players().insert(Player::new(ctx.sender));
+    match ctx.db.accounts().identity().find(ctx.sender) {
+        Some(account) => {
+            ctx.db.accounts().identity().update(Account {
+                last_login: ctx.timestamp,
+                ..account
+            });
+        }
+        None => {
+            ctx.db.accounts().insert(Account {
+                identity: ctx.sender,
+                created_at: ctx.timestamp,
+                last_login: ctx.timestamp,
+            });
+        }
+    }
 
-    let position = PlayerPosition::new(ctx.sender, 0.0, 0.0, 0.0);
-    ctx.db.players_positions().insert(position);
-    ctx.db.players_positions_lr().insert(position);
-
-    ctx.db.players_windows().insert(PlayerWindow::new(
-        // ctx.sender, position.x, position.z, 256.0, 128.0,
-        ctx.sender, position.x, position.z, 64.0, 32.0,
-    ));
+    // A session still around means the module stopped before the player got disconnected,
+    // its hot data is more recent than what was saved to cold storage
+    if let Some(session) = ctx.db.sessions().identity().find(ctx.sender) {
+        log::warn!("{} reconnected with a stale session", ctx.sender);
+        end_session(ctx, &session);
+    }
 }
 
 #[reducer(client_disconnected)]
 fn on_disconnected(ctx: &ReducerContext) {
-    let mut player = ctx.db.players().id().find(ctx.sender).unwrap();
-    let position = ctx.db.players_positions().id().find(ctx.sender).unwrap();
-
-    player.online = false;
-
-    // Save the player's data to cold storage
-    player.x = position.x;
-    player.y = position.y;
-    player.z = position.z;
-
-    ctx.db.players().id().update(player);
-
-    // Clear the player's hot data
-    ctx.db.players_windows().id().delete(ctx.sender);
-    ctx.db.players_positions().id().delete(ctx.sender);
-    ctx.db.players_positions_lr().id().delete(ctx.sender);
+    if let Some(session) = ctx.db.sessions().identity().find(ctx.sender) {
+        end_session(ctx, &session);
+    }
 }
@@ -0,0 +1,316 @@
+use std::time::Duration;
+
+use crate::tables::{
+    players_inputs::PlayerInput,
+    players_positions::{MovementState, PlayerPosition},
+};
+
+// Must match local_player::PLAYER_WALK_SPEED, PLAYER_RUN_SPEED and PLAYER_JUMP_HEIGHT on the client
+pub const PLAYER_WALK_SPEED: f32 = 4.0;
+pub const PLAYER_RUN_SPEED: f32 = 10.0;
+pub const PLAYER_JUMP_HEIGHT: f32 = 2.0;
+const GRAVITY: f32 = 9.81;
+// The world is flat for now, players rest at the float height of the client's character controller
+pub const GROUND_HEIGHT: f32 = 0.51;
+// The ground is a square centered on the origin, must match the plane spawned by world.rs on the client
+pub const WORLD_HALF_SIZE: f32 = 64.0;
+// Longest client frame a single input can cover
+pub const MAX_INPUT_DT: f32 = 0.1;
+// Jumping and falling are not driven by the run speed
+pub const MAX_VERTICAL_SPEED: f32 = 30.0;
+// Allowed overspeed to account for client frame timings
+const SPEED_TOLERANCE: f32 = 1.2;
+// Distance a player can bank on top of its speed budget to absorb network jitter between
+// two moves, unused distance carries over to the next move up to this limit
+const DISTANCE_SLACK: f32 = 1.0;
+// Idle time does not accumulate into a bigger movement budget past this duration
+const MAX_ELAPSED: Duration = Duration::from_millis(500);
+// Simulated time a player can bank, enough to absorb a burst of inputs held back by the network
+const MAX_INPUT_BUDGET: f32 = 0.6;
+
+pub struct ValidatedMove {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    // Horizontal distance left to the player for its next move
+    pub budget: f32,
+    pub clamped: bool,
+}
+
+// Clamp a move so that it stays within the world and the distance a player running at max_speed
+// can travel in the elapsed time. The budget is the distance left over from the previous moves,
+// so sending many small moves doesn't go any further than a single big one
+pub fn validate_move(
+    from: (f32, f32, f32),
+    to: (f32, f32, f32),
+    elapsed: Duration,
+    max_speed: f32,
+    budget: f32,
+) -> ValidatedMove {
+    let elapsed = elapsed.min(MAX_ELAPSED).as_secs_f32();
+    let max_budget = max_speed * SPEED_TOLERANCE * MAX_ELAPSED.as_secs_f32() + DISTANCE_SLACK;
+    let max_horizontal = (budget + max_speed * SPEED_TOLERANCE * elapsed).min(max_budget);
+    let max_vertical = MAX_VERTICAL_SPEED * SPEED_TOLERANCE * elapsed;
+
+    let mut validated = ValidatedMove {
+        x: to.0,
+        y: to.1,
+        z: to.2,
+        budget: max_horizontal,
+        clamped: false,
+    };
+
+    let dx = to.0 - from.0;
+    let dz = to.2 - from.2;
+    let horizontal = (dx * dx + dz * dz).sqrt();
+    if !horizontal.is_finite() {
+        validated.x = from.0;
+        validated.z = from.2;
+        validated.clamped = true;
+    } else if horizontal > max_horizontal {
+        let scale = max_horizontal / horizontal;
+        validated.x = from.0 + dx * scale;
+        validated.z = from.2 + dz * scale;
+        validated.budget = 0.0;
+        validated.clamped = true;
+    } else {
+        validated.budget -= horizontal;
+    }
+
+    let dy = to.1 - from.1;
+    if !dy.is_finite() {
+        validated.y = from.1;
+        validated.clamped = true;
+    } else if dy.abs() > max_vertical {
+        validated.y = from.1 + dy.signum() * max_vertical;
+        validated.clamped = true;
+    }
+
+    if !is_within_world(validated.x, validated.z) {
+        validated.x = validated.x.clamp(-WORLD_HALF_SIZE, WORLD_HALF_SIZE);
+        validated.z = validated.z.clamp(-WORLD_HALF_SIZE, WORLD_HALF_SIZE);
+        validated.clamped = true;
+    }
+
+    validated
+}
+
+pub fn is_within_world(x: f32, z: f32) -> bool {
+    x.abs() <= WORLD_HALF_SIZE && z.abs() <= WORLD_HALF_SIZE
+}
+
+// Simulated time a player's inputs can cover, so that clients can't speed up by sending inputs
+// faster than real time. The time left unused by the previous ticks carries over
+pub fn input_time_budget(carried: f32, elapsed: Duration) -> f32 {
+    (carried + elapsed.as_secs_f32() * SPEED_TOLERANCE).min(MAX_INPUT_BUDGET)
+}
+
+// Whether an input can be simulated, its direction is normalized by `simulate_input`
+pub fn is_valid_input(direction_x: f32, direction_z: f32, yaw: f32, dt: f32) -> bool {
+    direction_x.is_finite()
+        && direction_z.is_finite()
+        && yaw.is_finite()
+        && dt > 0.0
+        && dt <= MAX_INPUT_DT
+}
+
+// Advance a player's position by one input, mirroring the client's character controller
+pub fn simulate_input(position: &mut PlayerPosition, input: &PlayerInput) {
+    let mut direction_x = input.direction_x;
+    let mut direction_z = input.direction_z;
+    let length = (direction_x * direction_x + direction_z * direction_z).sqrt();
+    if length > 1.0 {
+        direction_x /= length;
+        direction_z /= length;
+    }
+
+    let speed = if input.run {
+        PLAYER_RUN_SPEED
+    } else {
+        PLAYER_WALK_SPEED
+    } * position.speed_multiplier;
+    let moving = length > 0.0;
+    // The edges of the world stop the player like walls
+    position.x =
+        (position.x + direction_x * speed * input.dt).clamp(-WORLD_HALF_SIZE, WORLD_HALF_SIZE);
+    position.z =
+        (position.z + direction_z * speed * input.dt).clamp(-WORLD_HALF_SIZE, WORLD_HALF_SIZE);
+    position.yaw = input.yaw;
+
+    let grounded = position.y <= GROUND_HEIGHT && position.velocity_y <= 0.0;
+    if grounded && input.jump {
+        position.velocity_y = (2.0 * GRAVITY * PLAYER_JUMP_HEIGHT).sqrt();
+    }
+
+    position.velocity_y -= GRAVITY * input.dt;
+    position.y += position.velocity_y * input.dt;
+    if position.y <= GROUND_HEIGHT {
+        position.y = GROUND_HEIGHT;
+        position.velocity_y = 0.0;
+    }
+
+    position.speed = speed * length.min(1.0);
+    position.movement = if position.velocity_y > 0.0 {
+        MovementState::Jumping
+    } else if position.y > GROUND_HEIGHT {
+        MovementState::Falling
+    } else if !moving {
+        MovementState::Standing
+    } else if input.run {
+        MovementState::Running
+    } else {
+        MovementState::Walking
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use spacetimedb::Timestamp;
+
+    use super::*;
+
+    const START: (f32, f32, f32) = (0.0, GROUND_HEIGHT, 0.0);
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!(
+            (actual - expected).abs() < 1e-4,
+            "expected {}, got {}",
+            expected,
+            actual
+        );
+    }
+
+    #[test]
+    fn accepts_moves_within_the_run_speed() {
+        let to = (1.0, GROUND_HEIGHT, 0.0);
+        let validated = validate_move(START, to, Duration::from_millis(200), PLAYER_RUN_SPEED, 0.0);
+
+        assert!(!validated.clamped);
+        assert_close(validated.x, 1.0);
+        // 10 m/s with the 20% tolerance over 200ms, minus the meter travelled
+        assert_close(validated.budget, 1.4);
+    }
+
+    #[test]
+    fn clamps_moves_past_the_run_speed() {
+        let to = (10.0, GROUND_HEIGHT, 0.0);
+        let validated = validate_move(START, to, Duration::from_millis(100), PLAYER_RUN_SPEED, 0.0);
+
+        assert!(validated.clamped);
+        assert_close(validated.x, 1.2);
+        assert_close(validated.z, 0.0);
+        assert_close(validated.budget, 0.0);
+    }
+
+    #[test]
+    fn many_small_moves_share_one_budget() {
+        let mut from = START;
+        let mut budget = DISTANCE_SLACK;
+        for _ in 0..100 {
+            let to = (from.0 + 1.0, from.1, from.2);
+            let validated =
+                validate_move(from, to, Duration::from_millis(1), PLAYER_RUN_SPEED, budget);
+            from = (validated.x, validated.y, validated.z);
+            budget = validated.budget;
+        }
+
+        // The slack is granted once, then the player only gets what 100ms of running allows
+        assert_close(from.0, DISTANCE_SLACK + 1.2);
+    }
+
+    #[test]
+    fn unused_budget_is_capped() {
+        let to = (100.0, GROUND_HEIGHT, 0.0);
+        let validated = validate_move(START, to, Duration::from_secs(10), PLAYER_RUN_SPEED, 1000.0);
+
+        assert!(validated.clamped);
+        assert_close(
+            validated.x,
+            PLAYER_RUN_SPEED * SPEED_TOLERANCE * 0.5 + DISTANCE_SLACK,
+        );
+    }
+
+    #[test]
+    fn rejects_non_finite_moves() {
+        let to = (f32::NAN, f32::INFINITY, 0.0);
+        let validated = validate_move(START, to, Duration::from_millis(100), PLAYER_RUN_SPEED, 0.0);
+
+        assert!(validated.clamped);
+        assert_close(validated.x, START.0);
+        assert_close(validated.y, START.1);
+        assert_close(validated.z, START.2);
+    }
+
+    #[test]
+    fn keeps_moves_within_the_world() {
+        let from = (WORLD_HALF_SIZE - 0.5, GROUND_HEIGHT, -WORLD_HALF_SIZE + 0.5);
+        let to = (WORLD_HALF_SIZE + 0.5, GROUND_HEIGHT, -WORLD_HALF_SIZE - 0.5);
+        let validated = validate_move(from, to, Duration::from_millis(100), PLAYER_RUN_SPEED, 0.0);
+
+        assert!(validated.clamped);
+        assert_close(validated.x, WORLD_HALF_SIZE);
+        assert_close(validated.z, -WORLD_HALF_SIZE);
+    }
+
+    #[test]
+    fn input_budget_follows_real_time() {
+        assert_close(input_time_budget(0.0, Duration::from_millis(50)), 0.06);
+        assert_close(input_time_budget(0.02, Duration::from_millis(50)), 0.08);
+        assert_close(
+            input_time_budget(0.0, Duration::from_secs(10)),
+            MAX_INPUT_BUDGET,
+        );
+    }
+
+    #[test]
+    fn ticks_dont_speed_up_the_simulation() {
+        let dt = 0.016;
+        let mut carried = 0.0;
+        let mut steps = 0;
+        // One second of 50ms ticks for a client flooding inputs
+        for _ in 0..20 {
+            let mut budget = input_time_budget(carried, Duration::from_millis(50));
+            while dt <= budget {
+                budget -= dt;
+                steps += 1;
+            }
+            carried = budget;
+        }
+
+        let simulated = steps as f32 * dt;
+        assert!(
+            simulated <= SPEED_TOLERANCE + 1e-4,
+            "simulated {}s",
+            simulated
+        );
+        // At most the input straddling the end of the second is left for the next tick
+        let expected = (SPEED_TOLERANCE / dt).round() as u32;
+        assert!(steps >= expected - 1, "simulated {} inputs", steps);
+    }
+
+    #[test]
+    fn simulated_players_stop_at_the_edge_of_the_world() {
+        let mut position = PlayerPosition::new(
+            1,
+            WORLD_HALF_SIZE - 0.1,
+            GROUND_HEIGHT,
+            0.0,
+            1.0,
+            Timestamp::UNIX_EPOCH,
+        );
+        let input = PlayerInput {
+            id: 1,
+            player_id: 1,
+            seq: 1,
+            direction_x: 1.0,
+            direction_z: 0.0,
+            yaw: 0.0,
+            run: true,
+            jump: false,
+            dt: MAX_INPUT_DT,
+        };
+        simulate_input(&mut position, &input);
+
+        assert_close(position.x, WORLD_HALF_SIZE);
+    }
+}
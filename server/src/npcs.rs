@@ -0,0 +1,439 @@
+use std::{f32::consts::TAU, time::Duration};
+
+use spacetimedb::{
+    log_stopwatch::LogStopwatch, rand::Rng, reducer, ReducerContext, Table, TimeDuration, Timestamp,
+};
+
+use crate::{
+    admin::{ensure_admin, ensure_scheduler},
+    combat::damage_player,
+    grid::cell_at,
+    movement::{is_within_world, GROUND_HEIGHT, PLAYER_WALK_SPEED, WORLD_HALF_SIZE},
+    spawn_regions::schedule_respawn,
+    tables::{
+        config::LodRing,
+        npcs::{
+            npc_types, npcs, npcs_positions, npcs_positions_lods, Npc, NpcAiTick, NpcBehavior,
+            NpcPosition, NpcPositionLod, NpcType,
+        },
+        players::players,
+        players_positions::{players_positions, MovementState, PlayerPosition},
+    },
+    world_items::spawn_world_item,
+};
+
+// Interval between two AI ticks, NPCs move by this much time on every tick
+pub const NPC_TICK_INTERVAL: Duration = Duration::from_millis(100);
+// Idle NPCs wait between WANDER_PAUSE_MIN_MS and WANDER_PAUSE_MAX_MS before wandering again
+const WANDER_PAUSE_MIN_MS: u64 = 2_000;
+const WANDER_PAUSE_MAX_MS: u64 = 6_000;
+// NPCs closer than this to their destination have reached it
+const ARRIVAL_DISTANCE: f32 = 0.25;
+
+#[reducer]
+fn spawn_npc(ctx: &ReducerContext, type_id: u32, x: f32, z: f32) -> Result<(), String> {
+    ensure_admin(ctx)?;
+    if !is_within_world(x, z) {
+        return Err(format!("Invalid position ({}, {})", x, z));
+    }
+
+    let npc_type = npc_type(ctx, type_id)?;
+    let npc = insert_npc(ctx, &npc_type, x, z, 0);
+    log::info!(
+        "{} spawned {} {} at ({}, {})",
+        ctx.sender,
+        npc.name,
+        npc.id,
+        x,
+        z
+    );
+    Ok(())
+}
+
+#[reducer]
+fn despawn_npc(ctx: &ReducerContext, npc_id: u64) -> Result<(), String> {
+    ensure_admin(ctx)?;
+    if ctx.db.npcs().id().find(npc_id).is_none() {
+        return Err(format!("NPC {} not found", npc_id));
+    }
+
+    delete_npc(ctx, npc_id);
+    Ok(())
+}
+
+#[reducer]
+pub fn tick_npcs(ctx: &ReducerContext, _row: NpcAiTick) -> Result<(), String> {
+    ensure_scheduler(ctx)?;
+    let _stopwatch = LogStopwatch::new("tick_npcs");
+    let rings = LodRing::all(ctx);
+    let types: Vec<NpcType> = ctx.db.npc_types().iter().collect();
+    // Living players in the world, the only ones NPCs can see
+    let players: Vec<PlayerPosition> = ctx
+        .db
+        .players_positions()
+        .iter()
+        .filter(|position| {
+            ctx.db
+                .players()
+                .id()
+                .find(position.id)
+                .is_some_and(|player| !player.dead)
+        })
+        .collect();
+    let mut total = 0;
+    let mut updated = 0;
+
+    for mut position in ctx.db.npcs_positions().iter() {
+        total += 1;
+        let Some(mut npc) = ctx.db.npcs().id().find(position.id) else {
+            continue;
+        };
+        let Some(npc_type) = types.iter().find(|npc_type| npc_type.id == npc.type_id) else {
+            continue;
+        };
+
+        let before = position;
+        advance_npc(ctx, &mut position, &npc, npc_type, &players);
+        if position == before {
+            continue;
+        }
+
+        // Leashed NPCs are back to full health once home
+        let healed = before.behavior == NpcBehavior::Returning
+            && position.behavior == NpcBehavior::Idle
+            && npc.health < npc.max_health;
+        let cell = cell_at(position.x, position.z);
+        if healed || npc.cell != cell {
+            if healed {
+                npc.health = npc.max_health;
+            }
+            npc.cell = cell;
+            ctx.db.npcs().id().update(npc);
+        }
+
+        if position.x != before.x || position.z != before.z {
+            position.last_update = ctx.timestamp;
+        }
+        ctx.db.npcs_positions().id().update(position);
+        sync_npc_lods(ctx, &position, &rings, false);
+        updated += 1;
+    }
+
+    log::debug!("tick_npcs: updated {} of {} NPCs", updated, total);
+    Ok(())
+}
+
+// Run the behavior of an NPC for one tick
+fn advance_npc(
+    ctx: &ReducerContext,
+    position: &mut NpcPosition,
+    npc: &Npc,
+    npc_type: &NpcType,
+    players: &[PlayerPosition],
+) {
+    let dt = NPC_TICK_INTERVAL.as_secs_f32();
+
+    match position.behavior {
+        NpcBehavior::Idle | NpcBehavior::Wandering => {
+            if let Some(target) = closest_player(position, players, npc_type.aggro_range) {
+                position.target = Some(target.id);
+                position.behavior = NpcBehavior::Chasing;
+                return;
+            }
+
+            if position.behavior == NpcBehavior::Idle {
+                if ctx.timestamp < position.next_wander_at {
+                    return;
+                }
+                let angle = ctx.rng().gen_range(0.0..TAU);
+                let distance = ctx.rng().gen_range(0.0..=npc_type.wander_radius);
+                position.destination_x = (position.home_x + angle.cos() * distance)
+                    .clamp(-WORLD_HALF_SIZE, WORLD_HALF_SIZE);
+                position.destination_z = (position.home_z + angle.sin() * distance)
+                    .clamp(-WORLD_HALF_SIZE, WORLD_HALF_SIZE);
+                position.behavior = NpcBehavior::Wandering;
+            }
+
+            let destination = (position.destination_x, position.destination_z);
+            if move_towards(position, destination, npc_type.walk_speed, dt) {
+                let pause = ctx
+                    .rng()
+                    .gen_range(WANDER_PAUSE_MIN_MS..=WANDER_PAUSE_MAX_MS);
+                position.next_wander_at = after(ctx, pause);
+                position.behavior = NpcBehavior::Idle;
+            }
+        }
+        NpcBehavior::Chasing | NpcBehavior::Attacking => {
+            let target = position
+                .target
+                .and_then(|target| players.iter().find(|player| player.id == target));
+            let Some(target) = target else {
+                return_home(position);
+                return;
+            };
+            if horizontal_distance(position, (position.home_x, position.home_z))
+                > npc_type.leash_range
+            {
+                return_home(position);
+                return;
+            }
+
+            if horizontal_distance(position, (target.x, target.z)) > npc_type.attack_range {
+                position.behavior = NpcBehavior::Chasing;
+                move_towards(position, (target.x, target.z), npc_type.run_speed, dt);
+                return;
+            }
+
+            position.behavior = NpcBehavior::Attacking;
+            stop(position);
+            position.yaw = yaw_towards(target.x - position.x, target.z - position.z);
+            if ctx.timestamp >= position.attack_ready_at && npc_type.damage > 0.0 {
+                hit_player(ctx, npc, target.id, npc_type.damage);
+                position.attack_ready_at = after(ctx, npc_type.attack_cooldown_ms);
+            }
+        }
+        NpcBehavior::Returning => {
+            let home = (position.home_x, position.home_z);
+            if move_towards(position, home, npc_type.run_speed, dt) {
+                position.behavior = NpcBehavior::Idle;
+                position.next_wander_at = after(ctx, WANDER_PAUSE_MIN_MS);
+            }
+        }
+    }
+}
+
+fn hit_player(ctx: &ReducerContext, npc: &Npc, player_id: u64, damage: f32) {
+    let Some(mut player) = ctx.db.players().id().find(player_id) else {
+        return;
+    };
+    // Another NPC may have killed the player earlier in the tick
+    if player.dead {
+        return;
+    }
+
+    damage_player(ctx, &mut player, damage, None);
+    log::info!(
+        "{} {} hit player {} for {}, {} health left",
+        npc.name,
+        npc.id,
+        player_id,
+        damage,
+        player.health
+    );
+    ctx.db.players().id().update(player);
+}
+
+// Closest player within range of the NPC
+fn closest_player<'a>(
+    position: &NpcPosition,
+    players: &'a [PlayerPosition],
+    range: f32,
+) -> Option<&'a PlayerPosition> {
+    players
+        .iter()
+        .map(|player| (player, horizontal_distance(position, (player.x, player.z))))
+        .filter(|(_, distance)| *distance <= range)
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(player, _)| player)
+}
+
+fn horizontal_distance(position: &NpcPosition, (x, z): (f32, f32)) -> f32 {
+    let dx = x - position.x;
+    let dz = z - position.z;
+    (dx * dx + dz * dz).sqrt()
+}
+
+// Move the NPC straight to the destination, returns whether it got there
+fn move_towards(position: &mut NpcPosition, (x, z): (f32, f32), speed: f32, dt: f32) -> bool {
+    let distance = horizontal_distance(position, (x, z));
+    if distance <= ARRIVAL_DISTANCE {
+        stop(position);
+        return true;
+    }
+
+    let step = (speed * dt).min(distance);
+    let dx = x - position.x;
+    let dz = z - position.z;
+    position.x += dx / distance * step;
+    position.z += dz / distance * step;
+    position.yaw = yaw_towards(dx, dz);
+    position.speed = speed;
+    position.movement = if speed > PLAYER_WALK_SPEED {
+        MovementState::Running
+    } else {
+        MovementState::Walking
+    };
+    false
+}
+
+fn stop(position: &mut NpcPosition) {
+    position.speed = 0.0;
+    position.movement = MovementState::Standing;
+}
+
+fn return_home(position: &mut NpcPosition) {
+    position.target = None;
+    position.behavior = NpcBehavior::Returning;
+}
+
+// Yaw facing the (dx, dz) direction, the models look towards +Z
+fn yaw_towards(dx: f32, dz: f32) -> f32 {
+    dx.atan2(dz)
+}
+
+fn after(ctx: &ReducerContext, ms: u64) -> Timestamp {
+    ctx.timestamp + TimeDuration::from_duration(Duration::from_millis(ms))
+}
+
+// Hurt an NPC, it turns on its attacker unless it is already busy with someone or heading home.
+// Killed NPCs leave their loot to the killer
+pub fn damage_npc(
+    ctx: &ReducerContext,
+    mut npc: Npc,
+    mut position: NpcPosition,
+    damage: f32,
+    attacker: u64,
+) {
+    npc.health = (npc.health - damage).max(0.0);
+    log::info!(
+        "Player {} hit {} {} for {}, {} health left",
+        attacker,
+        npc.name,
+        npc.id,
+        damage,
+        npc.health
+    );
+    if npc.health <= 0.0 {
+        kill_npc(ctx, &npc, &position, attacker);
+        return;
+    }
+    ctx.db.npcs().id().update(npc);
+
+    if position.target.is_none() && position.behavior != NpcBehavior::Returning {
+        position.target = Some(attacker);
+        position.behavior = NpcBehavior::Chasing;
+        ctx.db.npcs_positions().id().update(position);
+    }
+}
+
+fn kill_npc(ctx: &ReducerContext, npc: &Npc, position: &NpcPosition, killer: u64) {
+    log::info!("{} {} killed by player {}", npc.name, npc.id, killer);
+
+    let loot = ctx
+        .db
+        .npc_types()
+        .id()
+        .find(npc.type_id)
+        .and_then(|npc_type| {
+            npc_type
+                .loot_item_id
+                .map(|item| (item, npc_type.loot_quantity))
+        });
+    if let Some((item_id, quantity)) = loot.filter(|(_, quantity)| *quantity > 0) {
+        spawn_world_item(
+            ctx,
+            item_id,
+            quantity,
+            (position.x, position.y - GROUND_HEIGHT, position.z),
+            Some(killer),
+        );
+    }
+
+    delete_npc(ctx, npc.id);
+    if npc.region_id != 0 {
+        schedule_respawn(ctx, npc.region_id);
+    }
+}
+
+pub fn npc_type(ctx: &ReducerContext, type_id: u32) -> Result<NpcType, String> {
+    ctx.db
+        .npc_types()
+        .id()
+        .find(type_id)
+        .ok_or(format!("NPC type {} not found", type_id))
+}
+
+// Create an NPC at full health, standing on the ground at the given position
+pub fn insert_npc(ctx: &ReducerContext, npc_type: &NpcType, x: f32, z: f32, region_id: u64) -> Npc {
+    let npc = ctx.db.npcs().insert(Npc {
+        id: 0,
+        type_id: npc_type.id,
+        name: npc_type.name.clone(),
+        health: npc_type.max_health,
+        max_health: npc_type.max_health,
+        cell: cell_at(x, z),
+        region_id,
+    });
+
+    let position = ctx.db.npcs_positions().insert(NpcPosition::new(
+        npc.id,
+        x,
+        GROUND_HEIGHT,
+        z,
+        ctx.timestamp,
+    ));
+    sync_npc_lods(ctx, &position, &LodRing::all(ctx), true);
+    npc
+}
+
+pub fn delete_npc(ctx: &ReducerContext, npc_id: u64) {
+    ctx.db.npcs().id().delete(npc_id);
+    ctx.db.npcs_positions().id().delete(npc_id);
+    ctx.db.npcs_positions_lods().npc_id().delete(npc_id);
+}
+
+// Refresh the snapshots of the NPCs that stopped within the distance of their ring,
+// called along with the flush of the players snapshots
+pub fn flush_npcs_lods(ctx: &ReducerContext, rings: &[LodRing]) -> usize {
+    let mut flushed = 0;
+
+    for mut snapshot in ctx.db.npcs_positions_lods().iter() {
+        let Some(ring) = rings.iter().find(|ring| ring.ring == snapshot.ring) else {
+            continue;
+        };
+        let Some(position) = ctx.db.npcs_positions().id().find(snapshot.npc_id) else {
+            continue;
+        };
+
+        if !snapshot.is_due(&position, ring, ctx.timestamp, true) {
+            continue;
+        }
+
+        snapshot.refresh(&position, ctx.timestamp);
+        ctx.db.npcs_positions_lods().id().update(snapshot);
+        flushed += 1;
+    }
+
+    flushed
+}
+
+// Same as sync_position_lods for the NPCs
+fn sync_npc_lods(ctx: &ReducerContext, position: &NpcPosition, rings: &[LodRing], force: bool) {
+    let snapshots: Vec<NpcPositionLod> = ctx
+        .db
+        .npcs_positions_lods()
+        .npc_id()
+        .filter(&position.id)
+        .collect();
+
+    for ring in rings {
+        match snapshots.iter().find(|snapshot| snapshot.ring == ring.ring) {
+            Some(snapshot) => {
+                if !force && !snapshot.is_due(position, ring, ctx.timestamp, false) {
+                    continue;
+                }
+
+                let mut snapshot = *snapshot;
+                snapshot.refresh(position, ctx.timestamp);
+                ctx.db.npcs_positions_lods().id().update(snapshot);
+            }
+            None => {
+                ctx.db.npcs_positions_lods().insert(NpcPositionLod::new(
+                    ring.ring,
+                    position,
+                    ctx.timestamp,
+                ));
+            }
+        }
+    }
+}
@@ -1,35 +1,572 @@
-use spacetimedb::{log_stopwatch::LogStopwatch, reducer, ReducerContext, Table};
+use std::time::Duration;
 
-use crate::tables::{
-    players_positions::{players_positions, players_positions_lr},
-    players_windows::{players_windows, PlayerWindowUpdate},
+use spacetimedb::{
+    log_stopwatch::LogStopwatch, reducer, Identity, ReducerContext, ScheduleAt, Table, TimeDuration,
 };
 
-const LR_UPDATE_THRESHOLD: u8 = 10; // Update LR positions every n updates
+use crate::{
+    admin::{ensure_admin, ensure_scheduler},
+    characters::current_character,
+    combat::ensure_alive,
+    movement::{
+        input_time_budget, is_valid_input, simulate_input, validate_move, PLAYER_RUN_SPEED,
+    },
+    npcs::flush_npcs_lods,
+    tables::{
+        config::{server_config, LodRing, ServerConfig},
+        movement_violations::{movement_violations, MovementViolation},
+        players::players,
+        players_inputs::{
+            players_inputs, players_inputs_acks, PlayerInput, PlayerInputAck, PlayerSimulationTick,
+        },
+        players_positions::{
+            players_autosaves, players_positions, players_positions_lods, MovementState,
+            PlayerAutosave, PlayerPosition, PlayerPositionLod, PlayerPositionLodFlush,
+        },
+        players_windows::{
+            players_windows, players_windows_cells, PlayerWindow, PlayerWindowCell,
+            PlayerWindowUpdate,
+        },
+    },
+};
 
 #[reducer]
-fn move_player(ctx: &ReducerContext, x: f32, y: f32, z: f32) {
-    let mut player = ctx.db.players_positions().id().find(ctx.sender).unwrap();
-    player.x = x;
-    player.y = y;
-    player.z = z;
+fn move_player(
+    ctx: &ReducerContext,
+    x: f32,
+    y: f32,
+    z: f32,
+    yaw: f32,
+    movement: MovementState,
+    speed: f32,
+) -> Result<(), String> {
+    let character_id = current_character(ctx)?;
+    ensure_alive(ctx, character_id)?;
+    let mut player = ctx
+        .db
+        .players_positions()
+        .id()
+        .find(character_id)
+        .ok_or("Player position not found")?;
+    let elapsed = ctx
+        .timestamp
+        .duration_since(player.last_update)
+        .unwrap_or_default();
 
-    if player.update_count >= LR_UPDATE_THRESHOLD {
-        player.update_count = 0;
-        ctx.db.players_positions_lr().id().update(player);
-        ctx.db.players_positions().id().update(player);
+    let max_speed = PLAYER_RUN_SPEED * player.speed_multiplier;
+    let validated = validate_move(
+        (player.x, player.y, player.z),
+        (x, y, z),
+        elapsed,
+        max_speed,
+        player.move_budget,
+    );
+    player.x = validated.x;
+    player.y = validated.y;
+    player.z = validated.z;
+    player.move_budget = validated.budget;
+    if yaw.is_finite() {
+        player.yaw = yaw;
+    }
+    player.movement = movement;
+    player.speed = if speed.is_finite() {
+        speed.clamp(0.0, max_speed)
     } else {
-        player.update_count += 1;
-        ctx.db.players_positions().id().update(player);
+        0.0
+    };
+    player.last_update = ctx.timestamp;
+    player.dirty = true;
+
+    ctx.db.players_positions().id().update(player);
+    sync_position_lods(ctx, &player, &LodRing::all(ctx), false);
+
+    if validated.clamped {
+        record_movement_violation(ctx, &player);
+    }
+
+    Ok(())
+}
+
+// Inputs a player can have waiting for the next simulation tick
+const MAX_QUEUED_INPUTS: usize = 64;
+
+#[reducer]
+#[allow(clippy::too_many_arguments)]
+fn submit_input(
+    ctx: &ReducerContext,
+    seq: u32,
+    direction_x: f32,
+    direction_z: f32,
+    yaw: f32,
+    run: bool,
+    jump: bool,
+    dt: f32,
+) -> Result<(), String> {
+    if !is_valid_input(direction_x, direction_z, yaw, dt) {
+        return Err(format!(
+            "Invalid input {} ({}, {}) over {}s",
+            seq, direction_x, direction_z, dt
+        ));
+    }
+
+    let character_id = current_character(ctx)?;
+    ensure_alive(ctx, character_id)?;
+    let queued: Vec<PlayerInput> = ctx
+        .db
+        .players_inputs()
+        .player_id()
+        .filter(&character_id)
+        .collect();
+    if queued.len() >= MAX_QUEUED_INPUTS {
+        return Err("Too many pending inputs".to_string());
     }
+
+    let last_seq = queued
+        .iter()
+        .map(|input| input.seq)
+        .max()
+        .or_else(|| {
+            ctx.db
+                .players_inputs_acks()
+                .player_id()
+                .find(character_id)
+                .map(|ack| ack.seq)
+        })
+        .unwrap_or(0);
+    if seq <= last_seq {
+        return Err(format!("Input {} is older than input {}", seq, last_seq));
+    }
+
+    ctx.db.players_inputs().insert(PlayerInput {
+        id: 0,
+        player_id: character_id,
+        seq,
+        direction_x,
+        direction_z,
+        yaw,
+        run,
+        jump,
+        dt,
+    });
+
+    Ok(())
+}
+
+#[reducer]
+fn set_view_distance(ctx: &ReducerContext, view_distance: f32) -> Result<(), String> {
+    if !view_distance.is_finite() {
+        return Err(format!("Invalid view distance {}", view_distance));
+    }
+
+    let mut window = ctx
+        .db
+        .players_windows()
+        .id()
+        .find(current_character(ctx)?)
+        .ok_or("Player window not found")?;
+    let config = ServerConfig::get(ctx);
+    let rings = LodRing::all(ctx);
+
+    // Unlike clamp, this can't panic on a config whose bounds are swapped
+    let view_distance = view_distance
+        .max(config.min_view_distance)
+        .min(config.max_view_distance);
+    window.resize(view_distance, &config, &rings);
+    sync_window_cells(ctx, &window, &config, &rings);
+    ctx.db.players_windows().id().update(window);
+
+    Ok(())
 }
 
 #[reducer]
-pub fn update_players_windows(ctx: &ReducerContext, _row: PlayerWindowUpdate) {
-    LogStopwatch::new("update_players_windows");
+pub fn update_players_windows(
+    ctx: &ReducerContext,
+    _row: PlayerWindowUpdate,
+) -> Result<(), String> {
+    ensure_scheduler(ctx)?;
+    let _stopwatch = LogStopwatch::new("update_players_windows");
+    let config = ServerConfig::get(ctx);
+    let rings = LodRing::all(ctx);
+    let mut total = 0;
+    let mut rewritten = 0;
+    let mut orphaned = 0;
+
     for mut window in ctx.db.players_windows().iter() {
-        let player = ctx.db.players_positions().id().find(window.id).unwrap();
+        total += 1;
+        // A window without a position can't follow anyone, drop it instead of failing the tick
+        let Some(player) = ctx.db.players_positions().id().find(window.id) else {
+            delete_window(ctx, &window);
+            orphaned += 1;
+            continue;
+        };
+        if !window.should_recompute(player.x, player.z) {
+            continue;
+        }
+
         window.recompute(player.x, player.z);
+        sync_window_cells(ctx, &window, &config, &rings);
         ctx.db.players_windows().id().update(window);
+        rewritten += 1;
+    }
+
+    if rewritten > 0 {
+        log::info!(
+            "update_players_windows: rewrote {} out of {} windows",
+            rewritten,
+            total
+        );
+    }
+    if orphaned > 0 {
+        log::warn!(
+            "update_players_windows: deleted {} windows without a position",
+            orphaned
+        );
+    }
+
+    Ok(())
+}
+
+#[reducer]
+pub fn flush_positions_lods(
+    ctx: &ReducerContext,
+    _row: PlayerPositionLodFlush,
+) -> Result<(), String> {
+    ensure_scheduler(ctx)?;
+    let _stopwatch = LogStopwatch::new("flush_positions_lods");
+    let rings = LodRing::all(ctx);
+    let mut flushed = 0;
+
+    for mut snapshot in ctx.db.players_positions_lods().iter() {
+        let Some(ring) = rings.iter().find(|ring| ring.ring == snapshot.ring) else {
+            continue;
+        };
+        let Some(position) = ctx.db.players_positions().id().find(snapshot.player_id) else {
+            continue;
+        };
+
+        if !snapshot.is_due(&position, ring, ctx.timestamp, true) {
+            continue;
+        }
+
+        snapshot.refresh(&position, ctx.timestamp);
+        ctx.db.players_positions_lods().id().update(snapshot);
+        flushed += 1;
+    }
+
+    flushed += flush_npcs_lods(ctx, &rings);
+
+    if flushed > 0 {
+        log::info!("flush_positions_lods: flushed {} snapshots", flushed);
+    }
+
+    Ok(())
+}
+
+#[reducer]
+pub fn simulate_players(ctx: &ReducerContext, _row: PlayerSimulationTick) -> Result<(), String> {
+    ensure_scheduler(ctx)?;
+    let _stopwatch = LogStopwatch::new("simulate_players");
+    let rings = LodRing::all(ctx);
+
+    for mut position in ctx.db.players_positions().iter() {
+        let mut inputs: Vec<PlayerInput> = ctx
+            .db
+            .players_inputs()
+            .player_id()
+            .filter(&position.id)
+            .collect();
+        if inputs.is_empty() {
+            continue;
+        }
+        inputs.sort_by_key(|input| input.seq);
+
+        // Inputs over the time budget stay queued for the next tick
+        let elapsed = ctx
+            .timestamp
+            .duration_since(position.last_update)
+            .unwrap_or_default();
+        let mut budget = input_time_budget(position.input_budget, elapsed);
+        let before = position;
+        let mut last_seq = None;
+        for input in inputs {
+            if input.dt > budget {
+                break;
+            }
+
+            budget -= input.dt;
+            simulate_input(&mut position, &input);
+            last_seq = Some(input.seq);
+            ctx.db.players_inputs().id().delete(input.id);
+        }
+
+        let Some(seq) = last_seq else {
+            continue;
+        };
+
+        // Idle clients keep sending inputs, only rewrite the players that moved or turned. The
+        // budget and its time stay as they were, the elapsed time is credited on the next write
+        let moved = (position.x, position.y, position.z) != (before.x, before.y, before.z);
+        if moved || position.yaw != before.yaw || position.movement != before.movement {
+            position.input_budget = budget;
+            position.last_update = ctx.timestamp;
+            position.dirty |= moved;
+            ctx.db.players_positions().id().update(position);
+            sync_position_lods(ctx, &position, &rings, false);
+        }
+        acknowledge_input(ctx, &position, seq);
+    }
+
+    Ok(())
+}
+
+// Saving more often would keep rewriting every moving player
+const MIN_AUTOSAVE_INTERVAL_MS: u64 = 1000;
+
+#[reducer]
+fn set_autosave_interval(ctx: &ReducerContext, interval_ms: u64) -> Result<(), String> {
+    ensure_admin(ctx)?;
+    if interval_ms < MIN_AUTOSAVE_INTERVAL_MS {
+        return Err(format!(
+            "Autosaves can't run more than once every {}ms",
+            MIN_AUTOSAVE_INTERVAL_MS
+        ));
+    }
+
+    let mut config = ctx
+        .db
+        .server_config()
+        .id()
+        .find(ServerConfig::ID)
+        .ok_or("Server config not found")?;
+    config.autosave_interval_ms = interval_ms;
+    ctx.db.server_config().id().update(config);
+    schedule_autosave(ctx, &config);
+
+    log::info!(
+        "{} set the autosave interval to {}ms",
+        ctx.sender,
+        interval_ms
+    );
+    Ok(())
+}
+
+#[reducer]
+pub fn autosave_players(ctx: &ReducerContext, _row: PlayerAutosave) -> Result<(), String> {
+    ensure_scheduler(ctx)?;
+    let _stopwatch = LogStopwatch::new("autosave_players");
+    let mut saved = 0;
+
+    let dirty: Vec<PlayerPosition> = ctx.db.players_positions().dirty().filter(&true).collect();
+    for mut position in dirty {
+        if let Some(mut player) = ctx.db.players().id().find(position.id) {
+            player.save_position(&position);
+            ctx.db.players().id().update(player);
+            saved += 1;
+        }
+
+        position.dirty = false;
+        ctx.db.players_positions().id().update(position);
+    }
+
+    log::info!("autosave_players: saved {} players", saved);
+    Ok(())
+}
+
+// Replace the autosave schedule with one following the interval of the config
+pub fn schedule_autosave(ctx: &ReducerContext, config: &ServerConfig) {
+    let interval = TimeDuration::from_duration(Duration::from_millis(config.autosave_interval_ms));
+    ctx.db.players_autosaves().id().delete(PlayerAutosave::ID);
+    ctx.db.players_autosaves().insert(PlayerAutosave {
+        id: PlayerAutosave::ID,
+        scheduled_at: ScheduleAt::Interval(interval),
+    });
+}
+
+// Publish the last simulated input of a player along with the resulting state
+fn acknowledge_input(ctx: &ReducerContext, position: &PlayerPosition, seq: u32) {
+    let ack = PlayerInputAck {
+        player_id: position.id,
+        seq,
+        x: position.x,
+        y: position.y,
+        z: position.z,
+        velocity_y: position.velocity_y,
+        acked_at: ctx.timestamp,
+    };
+
+    match ctx.db.players_inputs_acks().player_id().find(position.id) {
+        Some(_) => {
+            ctx.db.players_inputs_acks().player_id().update(ack);
+        }
+        None => {
+            ctx.db.players_inputs_acks().insert(ack);
+        }
+    }
+}
+
+// Move a player without going through the movement validation. The acknowledged input is
+// refreshed with the new position so that the client snaps to it
+pub fn teleport_player(
+    ctx: &ReducerContext,
+    position: &mut PlayerPosition,
+    x: f32,
+    y: f32,
+    z: f32,
+) {
+    position.x = x;
+    position.y = y;
+    position.z = z;
+    position.velocity_y = 0.0;
+    position.movement = MovementState::Standing;
+    position.speed = 0.0;
+    position.last_update = ctx.timestamp;
+    position.dirty = true;
+    ctx.db.players_positions().id().update(*position);
+    sync_position_lods(ctx, position, &LodRing::all(ctx), true);
+
+    ctx.db.players_inputs().player_id().delete(position.id);
+    if let Some(ack) = ctx.db.players_inputs_acks().player_id().find(position.id) {
+        acknowledge_input(ctx, position, ack.seq);
+    }
+}
+
+// Keep track of the player's violations and send the authoritative position back to the client
+fn record_movement_violation(ctx: &ReducerContext, position: &PlayerPosition) {
+    log::warn!(
+        "Rejected move of player {}, corrected to ({}, {}, {})",
+        position.id,
+        position.x,
+        position.y,
+        position.z
+    );
+
+    match ctx.db.movement_violations().player_id().find(position.id) {
+        Some(violation) => {
+            ctx.db
+                .movement_violations()
+                .player_id()
+                .update(MovementViolation {
+                    count: violation.count + 1,
+                    last_violation: ctx.timestamp,
+                    x: position.x,
+                    y: position.y,
+                    z: position.z,
+                    ..violation
+                });
+        }
+        None => {
+            ctx.db.movement_violations().insert(MovementViolation {
+                player_id: position.id,
+                count: 1,
+                last_violation: ctx.timestamp,
+                x: position.x,
+                y: position.y,
+                z: position.z,
+            });
+        }
+    }
+}
+
+// Refresh the snapshots of a player's position for every ring due for an update,
+// missing snapshots are created so that rings can be added without migrating players
+pub fn sync_position_lods(
+    ctx: &ReducerContext,
+    position: &PlayerPosition,
+    rings: &[LodRing],
+    force: bool,
+) {
+    let snapshots: Vec<PlayerPositionLod> = ctx
+        .db
+        .players_positions_lods()
+        .player_id()
+        .filter(&position.id)
+        .collect();
+
+    for ring in rings {
+        match snapshots.iter().find(|snapshot| snapshot.ring == ring.ring) {
+            Some(snapshot) => {
+                if !force && !snapshot.is_due(position, ring, ctx.timestamp, false) {
+                    continue;
+                }
+
+                let mut snapshot = *snapshot;
+                snapshot.refresh(position, ctx.timestamp);
+                ctx.db.players_positions_lods().id().update(snapshot);
+            }
+            None => {
+                ctx.db
+                    .players_positions_lods()
+                    .insert(PlayerPositionLod::new(ring.ring, position, ctx.timestamp));
+            }
+        }
+    }
+}
+
+// Create the rows tracking an online player, its snapshots and its window
+pub fn insert_hot_data(
+    ctx: &ReducerContext,
+    position: PlayerPosition,
+    identity: Identity,
+    view_distance: f32,
+    config: &ServerConfig,
+    rings: &[LodRing],
+) {
+    ctx.db.players_positions().insert(position);
+    sync_position_lods(ctx, &position, rings, true);
+
+    let window = ctx.db.players_windows().insert(PlayerWindow::new(
+        position.id,
+        identity,
+        position.x,
+        position.z,
+        view_distance,
+        config,
+        rings,
+    ));
+    sync_window_cells(ctx, &window, config, rings);
+}
+
+pub fn delete_window(ctx: &ReducerContext, window: &PlayerWindow) {
+    ctx.db.players_windows().id().delete(window.id);
+    ctx.db.players_windows_cells().player_id().delete(window.id);
+}
+
+// Bring the cells of a window in line with its center, only touching the cells that changed
+pub fn sync_window_cells(
+    ctx: &ReducerContext,
+    window: &PlayerWindow,
+    config: &ServerConfig,
+    rings: &[LodRing],
+) {
+    let mut wanted = window.cells(config, rings);
+    let existing_cells: Vec<PlayerWindowCell> = ctx
+        .db
+        .players_windows_cells()
+        .player_id()
+        .filter(&window.id)
+        .collect();
+
+    for existing in existing_cells {
+        match wanted
+            .iter()
+            .position(|&(cell, ring)| cell == existing.cell && ring == existing.ring)
+        {
+            Some(index) => {
+                wanted.swap_remove(index);
+            }
+            None => {
+                ctx.db.players_windows_cells().id().delete(existing.id);
+            }
+        }
+    }
+
+    for (cell, ring) in wanted {
+        ctx.db.players_windows_cells().insert(PlayerWindowCell {
+            id: 0,
+            player_id: window.id,
+            identity: window.identity,
+            cell,
+            ring,
+        });
     }
 }
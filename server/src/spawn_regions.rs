@@ -0,0 +1,263 @@
+use std::time::Duration;
+
+use spacetimedb::{
+    log_stopwatch::LogStopwatch, rand::Rng, reducer, ReducerContext, Table, TimeDuration,
+};
+
+use crate::{
+    admin::{ensure_admin, ensure_scheduler},
+    movement::WORLD_HALF_SIZE,
+    npcs::{delete_npc, insert_npc, npc_type},
+    tables::npcs::{
+        npcs, npcs_positions, npcs_respawns, spawn_regions, Npc, NpcRespawn, SpawnRegion,
+        SpawnRegionTick,
+    },
+};
+
+// Interval between two checks of the regions population
+pub const SPAWN_REGIONS_INTERVAL: Duration = Duration::from_secs(1);
+// Keeps a single region from flooding the AI tick
+const MAX_ALIVE_PER_REGION: u32 = 50;
+
+#[reducer]
+#[allow(clippy::too_many_arguments)]
+fn create_spawn_region(
+    ctx: &ReducerContext,
+    name: String,
+    min_x: f32,
+    min_z: f32,
+    max_x: f32,
+    max_z: f32,
+    npc_type_id: u32,
+    max_alive: u32,
+    respawn_delay_ms: u64,
+) -> Result<(), String> {
+    ensure_admin(ctx)?;
+    let region = validate_region(
+        ctx,
+        SpawnRegion {
+            id: 0,
+            name,
+            min_x,
+            min_z,
+            max_x,
+            max_z,
+            npc_type_id,
+            max_alive,
+            respawn_delay_ms,
+        },
+    )?;
+
+    let region = ctx.db.spawn_regions().insert(region);
+    log::info!(
+        "{} created spawn region {} {}",
+        ctx.sender,
+        region.id,
+        region.name
+    );
+    Ok(())
+}
+
+// The NPCs that no longer fit the region are despawned, the next population check fills it up again
+#[reducer]
+#[allow(clippy::too_many_arguments)]
+fn update_spawn_region(
+    ctx: &ReducerContext,
+    region_id: u64,
+    name: String,
+    min_x: f32,
+    min_z: f32,
+    max_x: f32,
+    max_z: f32,
+    npc_type_id: u32,
+    max_alive: u32,
+    respawn_delay_ms: u64,
+) -> Result<(), String> {
+    ensure_admin(ctx)?;
+    spawn_region(ctx, region_id)?;
+    let region = validate_region(
+        ctx,
+        SpawnRegion {
+            id: region_id,
+            name,
+            min_x,
+            min_z,
+            max_x,
+            max_z,
+            npc_type_id,
+            max_alive,
+            respawn_delay_ms,
+        },
+    )?;
+
+    let mut kept = 0;
+    let population: Vec<Npc> = ctx.db.npcs().region_id().filter(&region_id).collect();
+    for npc in population {
+        let inside = ctx
+            .db
+            .npcs_positions()
+            .id()
+            .find(npc.id)
+            .is_some_and(|position| region.contains(position.home_x, position.home_z));
+        if npc.type_id != region.npc_type_id || !inside || kept >= region.max_alive {
+            delete_npc(ctx, npc.id);
+            continue;
+        }
+        kept += 1;
+    }
+
+    let mut respawns: Vec<NpcRespawn> = ctx
+        .db
+        .npcs_respawns()
+        .region_id()
+        .filter(&region_id)
+        .collect();
+    respawns.sort_by_key(|respawn| respawn.respawn_at);
+    for respawn in respawns
+        .iter()
+        .skip(region.max_alive.saturating_sub(kept) as usize)
+    {
+        ctx.db.npcs_respawns().id().delete(respawn.id);
+    }
+
+    log::info!(
+        "{} updated spawn region {} {}",
+        ctx.sender,
+        region.id,
+        region.name
+    );
+    ctx.db.spawn_regions().id().update(region);
+    Ok(())
+}
+
+#[reducer]
+fn delete_spawn_region(ctx: &ReducerContext, region_id: u64) -> Result<(), String> {
+    ensure_admin(ctx)?;
+    let region = spawn_region(ctx, region_id)?;
+
+    let population: Vec<u64> = ctx
+        .db
+        .npcs()
+        .region_id()
+        .filter(&region_id)
+        .map(|npc| npc.id)
+        .collect();
+    for npc_id in population {
+        delete_npc(ctx, npc_id);
+    }
+    ctx.db.npcs_respawns().region_id().delete(region_id);
+    ctx.db.spawn_regions().id().delete(region_id);
+
+    log::info!(
+        "{} deleted spawn region {} {}",
+        ctx.sender,
+        region.id,
+        region.name
+    );
+    Ok(())
+}
+
+// Spawn the NPCs missing from every region, killed NPCs are only replaced once their respawn is due
+#[reducer]
+pub fn populate_spawn_regions(ctx: &ReducerContext, _row: SpawnRegionTick) -> Result<(), String> {
+    ensure_scheduler(ctx)?;
+    let _stopwatch = LogStopwatch::new("populate_spawn_regions");
+    let mut spawned = 0;
+
+    for region in ctx.db.spawn_regions().iter() {
+        let respawns: Vec<NpcRespawn> = ctx
+            .db
+            .npcs_respawns()
+            .region_id()
+            .filter(&region.id)
+            .collect();
+        let mut waiting = 0;
+        for respawn in respawns {
+            if respawn.respawn_at <= ctx.timestamp {
+                ctx.db.npcs_respawns().id().delete(respawn.id);
+            } else {
+                waiting += 1;
+            }
+        }
+
+        let alive = ctx.db.npcs().region_id().filter(&region.id).count() as u32;
+        let missing = region.max_alive.saturating_sub(alive + waiting);
+        if missing == 0 {
+            continue;
+        }
+
+        let Ok(npc_type) = npc_type(ctx, region.npc_type_id) else {
+            log::warn!(
+                "Spawn region {} uses unknown NPC type {}",
+                region.id,
+                region.npc_type_id
+            );
+            continue;
+        };
+        for _ in 0..missing {
+            let x = ctx.rng().gen_range(region.min_x..=region.max_x);
+            let z = ctx.rng().gen_range(region.min_z..=region.max_z);
+            insert_npc(ctx, &npc_type, x, z, region.id);
+            spawned += 1;
+        }
+    }
+
+    if spawned > 0 {
+        log::info!("populate_spawn_regions: spawned {} NPCs", spawned);
+    }
+
+    Ok(())
+}
+
+// Keep the spot of a killed NPC reserved until the respawn delay of its region is over
+pub fn schedule_respawn(ctx: &ReducerContext, region_id: u64) {
+    let Some(region) = ctx.db.spawn_regions().id().find(region_id) else {
+        return;
+    };
+
+    let delay = TimeDuration::from_duration(Duration::from_millis(region.respawn_delay_ms));
+    ctx.db.npcs_respawns().insert(NpcRespawn {
+        id: 0,
+        region_id,
+        respawn_at: ctx.timestamp + delay,
+    });
+}
+
+fn spawn_region(ctx: &ReducerContext, region_id: u64) -> Result<SpawnRegion, String> {
+    ctx.db
+        .spawn_regions()
+        .id()
+        .find(region_id)
+        .ok_or(format!("Spawn region {} not found", region_id))
+}
+
+fn validate_region(ctx: &ReducerContext, mut region: SpawnRegion) -> Result<SpawnRegion, String> {
+    region.name = region.name.trim().to_string();
+    if region.name.is_empty() {
+        return Err("Spawn regions need a name".to_string());
+    }
+
+    let bounds = [region.min_x, region.min_z, region.max_x, region.max_z];
+    if bounds
+        .iter()
+        .any(|bound| !bound.is_finite() || bound.abs() > WORLD_HALF_SIZE)
+    {
+        return Err(format!(
+            "Spawn region bounds must be within -{} and {}",
+            WORLD_HALF_SIZE, WORLD_HALF_SIZE
+        ));
+    }
+    if region.min_x > region.max_x || region.min_z > region.max_z {
+        return Err("Spawn region minimum bounds are past the maximum ones".to_string());
+    }
+
+    if region.max_alive > MAX_ALIVE_PER_REGION {
+        return Err(format!(
+            "Spawn regions hold at most {} NPCs",
+            MAX_ALIVE_PER_REGION
+        ));
+    }
+    npc_type(ctx, region.npc_type_id)?;
+
+    Ok(region)
+}
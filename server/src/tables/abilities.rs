@@ -0,0 +1,65 @@
+use spacetimedb::{table, Timestamp};
+
+// Definition of an ability players can cast on each other
+#[table(name = abilities, public)]
+#[derive(Clone)]
+pub struct Ability {
+    #[primary_key]
+    pub id: u32,
+    pub name: String,
+    // Maximum distance between the caster and its target
+    pub range: f32,
+    pub cooldown_ms: u64,
+    pub mana_cost: f32,
+    pub damage: f32,
+}
+
+impl Ability {
+    pub fn defaults() -> [Ability; 3] {
+        [
+            Ability {
+                id: 1,
+                name: "Strike".to_string(),
+                range: 3.0,
+                cooldown_ms: 1_500,
+                mana_cost: 0.0,
+                damage: 10.0,
+            },
+            Ability {
+                id: 2,
+                name: "Fireball".to_string(),
+                range: 30.0,
+                cooldown_ms: 3_000,
+                mana_cost: 20.0,
+                damage: 25.0,
+            },
+            Ability {
+                id: 3,
+                name: "Backstab".to_string(),
+                range: 3.0,
+                cooldown_ms: 8_000,
+                mana_cost: 10.0,
+                damage: 40.0,
+            },
+        ]
+    }
+}
+
+// Time at which a player can cast an ability again, kept across sessions until it elapsed
+#[table(name = abilities_cooldowns, public)]
+#[derive(Clone, Copy)]
+pub struct AbilityCooldown {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub player_id: u64,
+    pub ability_id: u32,
+    pub ready_at: Timestamp,
+}
+
+impl AbilityCooldown {
+    pub fn is_ready(&self, now: Timestamp) -> bool {
+        self.ready_at <= now
+    }
+}
@@ -0,0 +1,51 @@
+use spacetimedb::{table, Identity, SpacetimeType, Timestamp};
+
+// A connection identity, owning up to MAX_CHARACTERS characters
+#[table(name = accounts)]
+pub struct Account {
+    #[primary_key]
+    pub identity: Identity,
+    pub created_at: Timestamp,
+    pub last_login: Timestamp,
+}
+
+// Identities allowed to run the admin reducers, starting with the one that published the module
+#[table(name = admins)]
+pub struct Admin {
+    #[primary_key]
+    pub identity: Identity,
+    pub added_at: Timestamp,
+}
+
+#[derive(SpacetimeType, Clone, Copy, PartialEq, Debug)]
+pub enum CharacterClass {
+    Warrior,
+    Mage,
+    Rogue,
+}
+
+#[table(name = characters, public)]
+pub struct Character {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub account: Identity,
+    // Capitalized by `create_character` so that names are unique regardless of case
+    #[unique]
+    pub name: String,
+    pub class: CharacterClass,
+    pub created_at: Timestamp,
+    pub last_login: Timestamp,
+}
+
+// The character an identity is currently playing, hot tables are keyed by its id
+#[table(name = sessions, public)]
+#[derive(Clone, Copy)]
+pub struct Session {
+    #[primary_key]
+    pub identity: Identity,
+    #[unique]
+    pub character_id: u64,
+    pub started_at: Timestamp,
+}
@@ -0,0 +1,43 @@
+use spacetimedb::{table, Identity, ScheduleAt, SpacetimeType, Timestamp};
+
+use crate::chat::cleanup_chat_messages;
+
+#[derive(SpacetimeType, Clone, Copy, PartialEq, Debug)]
+pub enum ChatChannel {
+    // Players whose interest window covers the cell of the sender
+    Local,
+    Global,
+    Whisper,
+}
+
+#[table(name = chat_messages, public)]
+pub struct ChatMessage {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub channel: ChatChannel,
+    // Character sending the message and the identity playing it
+    pub sender_id: u64,
+    pub sender_name: String,
+    #[index(btree)]
+    pub sender: Identity,
+    // Identity whispered to, zero for the other channels
+    #[index(btree)]
+    pub recipient: Identity,
+    pub recipient_name: String,
+    // Cell of the sender when the message was sent, local messages are heard from there
+    #[index(btree)]
+    pub cell: u64,
+    // Mirrors of the channel checked by the visibility filters
+    pub global: bool,
+    pub local: bool,
+    pub text: String,
+    pub sent_at: Timestamp,
+}
+
+#[table(name = chat_cleanups, private, scheduled(cleanup_chat_messages))]
+pub struct ChatCleanup {
+    #[primary_key]
+    pub id: u64,
+    pub scheduled_at: ScheduleAt,
+}
@@ -0,0 +1,103 @@
+use spacetimedb::{table, ReducerContext, Table};
+
+#[table(name = server_config, public)]
+#[derive(Clone, Copy)]
+pub struct ServerConfig {
+    #[primary_key]
+    pub id: u32,
+    pub default_view_distance: f32,
+    pub min_view_distance: f32,
+    pub max_view_distance: f32,
+    // Interval at which moved players are saved to cold storage, changed with set_autosave_interval
+    pub autosave_interval_ms: u64,
+    // Time dead players wait before coming back at the spawn point
+    pub respawn_delay_ms: u64,
+    // Health and mana regenerated per second by living players
+    pub health_regen_per_second: f32,
+    pub mana_regen_per_second: f32,
+    // Regeneration is multiplied by this once the player stayed out of combat long enough
+    pub out_of_combat_regen_multiplier: f32,
+    pub out_of_combat_delay_ms: u64,
+    // Chat messages older than this are deleted by the next cleanup
+    pub chat_retention_ms: u64,
+    // Items left on the ground despawn after this long
+    pub world_item_lifetime_ms: u64,
+    // Loot dropped for a character can only be picked up by them for this long
+    pub loot_lock_ms: u64,
+    // Furthest distance at which items on the ground can be picked up
+    pub pickup_range: f32,
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            id: ServerConfig::ID,
+            default_view_distance: 64.0,
+            min_view_distance: 32.0,
+            max_view_distance: 256.0,
+            autosave_interval_ms: 30_000,
+            respawn_delay_ms: 10_000,
+            health_regen_per_second: 1.0,
+            mana_regen_per_second: 2.0,
+            out_of_combat_regen_multiplier: 5.0,
+            out_of_combat_delay_ms: 6_000,
+            chat_retention_ms: 300_000,
+            world_item_lifetime_ms: 120_000,
+            loot_lock_ms: 30_000,
+            pickup_range: 3.0,
+        }
+    }
+}
+
+impl ServerConfig {
+    pub const ID: u32 = 0;
+
+    pub fn get(ctx: &ReducerContext) -> Self {
+        ctx.db
+            .server_config()
+            .id()
+            .find(Self::ID)
+            .unwrap_or_default()
+    }
+}
+
+// A level of detail ring of the interest window, ring 0 is the innermost one.
+// A cell belongs to the smallest ring covering it, so rings never overlap
+#[table(name = lod_rings, public)]
+#[derive(Clone, Copy)]
+pub struct LodRing {
+    #[primary_key]
+    pub ring: u8,
+    // Size of the ring for a player using the default view distance
+    pub size: f32,
+    // Positions in this ring are refreshed at most once per interval
+    pub update_interval_ms: u64,
+    // and only once the player moved further than this distance from the last snapshot
+    pub min_distance: f32,
+}
+
+impl LodRing {
+    pub fn defaults() -> [LodRing; 2] {
+        [
+            LodRing {
+                ring: 0,
+                size: 32.0,
+                update_interval_ms: 0,
+                min_distance: 0.0,
+            },
+            LodRing {
+                ring: 1,
+                size: 64.0,
+                update_interval_ms: 1000,
+                min_distance: 2.0,
+            },
+        ]
+    }
+
+    // All the rings ordered from the innermost to the outermost one
+    pub fn all(ctx: &ReducerContext) -> Vec<LodRing> {
+        let mut rings: Vec<LodRing> = ctx.db.lod_rings().iter().collect();
+        rings.sort_by_key(|ring| ring.ring);
+        rings
+    }
+}
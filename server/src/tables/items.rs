@@ -0,0 +1,142 @@
+use spacetimedb::{table, ScheduleAt, SpacetimeType, Timestamp};
+
+use crate::world_items::despawn_world_items;
+
+#[derive(SpacetimeType, Clone, Copy, PartialEq, Debug)]
+pub enum EquipmentSlot {
+    Head,
+    Chest,
+    Legs,
+    Feet,
+    Weapon,
+}
+
+// Kind of item players can carry, stacks of it hold up to max_stack items
+#[table(name = item_definitions, public)]
+#[derive(Clone)]
+pub struct ItemDefinition {
+    #[primary_key]
+    pub id: u32,
+    pub name: String,
+    pub max_stack: u32,
+    // Slot the item is worn in, items without one can't be equipped
+    pub equipment_slot: Option<EquipmentSlot>,
+    // Stats granted while the item is equipped
+    pub bonus_health: f32,
+    pub bonus_mana: f32,
+    // Fraction added to the movement speed
+    pub bonus_speed: f32,
+    pub bonus_damage: f32,
+}
+
+impl ItemDefinition {
+    pub const HEALTH_POTION: u32 = 1;
+    pub const MANA_POTION: u32 = 2;
+    pub const GOLD_COIN: u32 = 5;
+
+    pub fn defaults() -> [ItemDefinition; 8] {
+        [
+            ItemDefinition::stackable(Self::HEALTH_POTION, "Health Potion", 20),
+            ItemDefinition::stackable(Self::MANA_POTION, "Mana Potion", 20),
+            ItemDefinition {
+                bonus_damage: 5.0,
+                ..ItemDefinition::equipment(3, "Iron Sword", EquipmentSlot::Weapon)
+            },
+            ItemDefinition {
+                bonus_health: 25.0,
+                ..ItemDefinition::equipment(4, "Leather Armor", EquipmentSlot::Chest)
+            },
+            ItemDefinition::stackable(Self::GOLD_COIN, "Gold Coin", 999),
+            ItemDefinition {
+                bonus_health: 10.0,
+                bonus_mana: 15.0,
+                ..ItemDefinition::equipment(6, "Leather Cap", EquipmentSlot::Head)
+            },
+            ItemDefinition {
+                bonus_health: 15.0,
+                ..ItemDefinition::equipment(7, "Leather Leggings", EquipmentSlot::Legs)
+            },
+            ItemDefinition {
+                bonus_speed: 0.1,
+                ..ItemDefinition::equipment(8, "Swift Boots", EquipmentSlot::Feet)
+            },
+        ]
+    }
+
+    fn stackable(id: u32, name: &str, max_stack: u32) -> Self {
+        Self {
+            id,
+            name: name.to_string(),
+            max_stack,
+            equipment_slot: None,
+            bonus_health: 0.0,
+            bonus_mana: 0.0,
+            bonus_speed: 0.0,
+            bonus_damage: 0.0,
+        }
+    }
+
+    fn equipment(id: u32, name: &str, slot: EquipmentSlot) -> Self {
+        Self {
+            equipment_slot: Some(slot),
+            ..Self::stackable(id, name, 1)
+        }
+    }
+}
+
+// Stack of items in a slot of a character's inventory, empty slots have no row
+#[table(name = inventory_slots, public)]
+#[derive(Clone, Copy)]
+pub struct InventorySlot {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    // Id of the character
+    #[index(btree)]
+    pub owner: u64,
+    pub slot: u32,
+    pub item_id: u32,
+    pub quantity: u32,
+}
+
+// Item worn by a character, taken out of its inventory while equipped
+#[table(name = equipped_items, public)]
+#[derive(Clone, Copy)]
+pub struct EquippedItem {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    // Id of the character
+    #[index(btree)]
+    pub owner: u64,
+    pub slot: EquipmentSlot,
+    pub item_id: u32,
+}
+
+// Stack of items lying on the ground until someone picks it up or it despawns
+#[table(name = world_items, public)]
+#[derive(Clone, Copy)]
+pub struct WorldItem {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub item_id: u32,
+    pub quantity: u32,
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    // Cell the item lies in, the visibility filter joins it with the windows cells
+    #[index(btree)]
+    pub cell: u64,
+    // Character the loot is reserved to until loot_locked_until, anyone can pick it up afterwards
+    pub loot_owner: Option<u64>,
+    pub loot_locked_until: Timestamp,
+    pub despawn_at: Timestamp,
+}
+
+#[table(name = world_items_despawns, private, scheduled(despawn_world_items))]
+pub struct WorldItemDespawn {
+    #[primary_key]
+    pub id: u64,
+    pub scheduled_at: ScheduleAt,
+}
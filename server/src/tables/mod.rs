@@ -1,3 +1,11 @@
+pub mod abilities;
+pub mod accounts;
+pub mod chat;
+pub mod config;
+pub mod items;
+pub mod movement_violations;
+pub mod npcs;
 pub mod players;
+pub mod players_inputs;
 pub mod players_positions;
 pub mod players_windows;
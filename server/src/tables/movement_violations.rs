@@ -0,0 +1,15 @@
+use spacetimedb::{table, Timestamp};
+
+// Moves rejected by the server for a player, the position is the authoritative one
+// the client has to snap back to
+#[table(name = movement_violations, public)]
+#[derive(Clone, Copy)]
+pub struct MovementViolation {
+    #[primary_key]
+    pub player_id: u64,
+    pub count: u32,
+    pub last_violation: Timestamp,
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
@@ -0,0 +1,348 @@
+use std::{f32::consts::TAU, time::Duration};
+
+use spacetimedb::{table, ScheduleAt, SpacetimeType, Timestamp};
+
+use crate::{
+    grid::cell_at,
+    npcs::tick_npcs,
+    spawn_regions::populate_spawn_regions,
+    tables::{config::LodRing, items::ItemDefinition, players_positions::MovementState},
+};
+
+// Facing changes smaller than this angle, in radians, don't refresh the snapshots
+const YAW_TOLERANCE: f32 = 0.05;
+
+// What an NPC is up to, advanced by the AI tick
+#[derive(SpacetimeType, Clone, Copy, PartialEq, Debug)]
+pub enum NpcBehavior {
+    Idle,
+    // Walking to a random point around home
+    Wandering,
+    Chasing,
+    Attacking,
+    // Gave up on its target, heads back home and heals there
+    Returning,
+}
+
+// Kind of NPC, every NPC of a type shares its stats and loot
+#[table(name = npc_types, public)]
+#[derive(Clone)]
+pub struct NpcType {
+    #[primary_key]
+    pub id: u32,
+    pub name: String,
+    pub max_health: f32,
+    pub damage: f32,
+    pub attack_range: f32,
+    pub attack_cooldown_ms: u64,
+    // Players closer than this get chased, NPCs with no aggro range only fight back
+    pub aggro_range: f32,
+    // Chases stop once the NPC is this far from home
+    pub leash_range: f32,
+    pub wander_radius: f32,
+    pub walk_speed: f32,
+    pub run_speed: f32,
+    // Dropped on death, reserved to the killer for a while
+    pub loot_item_id: Option<u32>,
+    pub loot_quantity: u32,
+}
+
+impl NpcType {
+    pub const WOLF: u32 = 1;
+    pub const GOBLIN: u32 = 2;
+    pub const BOAR: u32 = 3;
+
+    pub fn defaults() -> [NpcType; 3] {
+        [
+            NpcType {
+                id: Self::WOLF,
+                name: "Wolf".to_string(),
+                max_health: 60.0,
+                damage: 6.0,
+                attack_range: 2.0,
+                attack_cooldown_ms: 1500,
+                aggro_range: 10.0,
+                leash_range: 30.0,
+                wander_radius: 8.0,
+                walk_speed: 2.5,
+                run_speed: 7.0,
+                loot_item_id: Some(ItemDefinition::GOLD_COIN),
+                loot_quantity: 3,
+            },
+            NpcType {
+                id: Self::GOBLIN,
+                name: "Goblin".to_string(),
+                max_health: 90.0,
+                damage: 10.0,
+                attack_range: 2.5,
+                attack_cooldown_ms: 2000,
+                aggro_range: 8.0,
+                leash_range: 25.0,
+                wander_radius: 6.0,
+                walk_speed: 2.0,
+                run_speed: 6.0,
+                loot_item_id: Some(ItemDefinition::HEALTH_POTION),
+                loot_quantity: 1,
+            },
+            NpcType {
+                id: Self::BOAR,
+                name: "Boar".to_string(),
+                max_health: 80.0,
+                damage: 8.0,
+                attack_range: 2.0,
+                attack_cooldown_ms: 1800,
+                aggro_range: 0.0,
+                leash_range: 20.0,
+                wander_radius: 10.0,
+                walk_speed: 1.5,
+                run_speed: 6.5,
+                loot_item_id: None,
+                loot_quantity: 0,
+            },
+        ]
+    }
+}
+
+// Stats of an NPC, sent to the players whose window covers the cell it stands in
+#[table(name = npcs, public)]
+#[derive(Clone)]
+pub struct Npc {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub type_id: u32,
+    pub name: String,
+    pub health: f32,
+    pub max_health: f32,
+    // Only rewritten when the NPC crosses into another cell
+    #[index(btree)]
+    pub cell: u64,
+    // Spawn region keeping the NPC populated, zero for the NPCs spawned by hand
+    #[index(btree)]
+    pub region_id: u64,
+}
+
+// Authoritative position and AI state of the NPCs, only visible to clients through npcs_positions_lods
+#[table(name = npcs_positions)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct NpcPosition {
+    // Id of the NPC
+    #[primary_key]
+    pub id: u64,
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    // Rotation around the vertical axis, in radians
+    pub yaw: f32,
+    pub movement: MovementState,
+    // Horizontal speed, in units per second
+    pub speed: f32,
+    pub behavior: NpcBehavior,
+    // Where the NPC spawned, it wanders around it and comes back to it after a chase
+    pub home_x: f32,
+    pub home_z: f32,
+    // Point the NPC is wandering to
+    pub destination_x: f32,
+    pub destination_z: f32,
+    // Player chased or attacked
+    pub target: Option<u64>,
+    // Idle NPCs start wandering again at this time
+    pub next_wander_at: Timestamp,
+    pub attack_ready_at: Timestamp,
+    // Time of the last move, snapshots are interpolated with it
+    pub last_update: Timestamp,
+}
+
+impl NpcPosition {
+    pub fn new(id: u64, x: f32, y: f32, z: f32, now: Timestamp) -> Self {
+        Self {
+            id,
+            x,
+            y,
+            z,
+            yaw: 0.0,
+            movement: MovementState::Standing,
+            speed: 0.0,
+            behavior: NpcBehavior::Idle,
+            home_x: x,
+            home_z: z,
+            destination_x: x,
+            destination_z: z,
+            target: None,
+            next_wander_at: now,
+            attack_ready_at: now,
+            last_update: now,
+        }
+    }
+}
+
+// Snapshot of an NPC's position refreshed at the rate of its LOD ring, like players_positions_lods
+#[table(name = npcs_positions_lods, public)]
+#[derive(Clone, Copy)]
+pub struct NpcPositionLod {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub npc_id: u64,
+    pub ring: u8,
+    #[index(btree)]
+    pub cell: u64,
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub yaw: f32,
+    pub movement: MovementState,
+    pub speed: f32,
+    pub updated_at: Timestamp,
+    pub sampled_at: Timestamp,
+}
+
+impl NpcPositionLod {
+    pub fn new(ring: u8, position: &NpcPosition, now: Timestamp) -> Self {
+        Self {
+            id: 0,
+            npc_id: position.id,
+            ring,
+            cell: cell_at(position.x, position.z),
+            x: position.x,
+            y: position.y,
+            z: position.z,
+            yaw: position.yaw,
+            movement: position.movement,
+            speed: position.speed,
+            updated_at: now,
+            sampled_at: position.last_update,
+        }
+    }
+
+    // Same rules as the players snapshots, see PlayerPositionLod::is_due
+    pub fn is_due(
+        &self,
+        position: &NpcPosition,
+        ring: &LodRing,
+        now: Timestamp,
+        flush: bool,
+    ) -> bool {
+        let elapsed = now.duration_since(self.updated_at).unwrap_or_default();
+        if elapsed < Duration::from_millis(ring.update_interval_ms) {
+            return false;
+        }
+
+        let dx = position.x - self.x;
+        let dy = position.y - self.y;
+        let dz = position.z - self.z;
+        let min_distance = if flush { 0.0 } else { ring.min_distance };
+        if dx * dx + dy * dy + dz * dz > min_distance * min_distance {
+            return true;
+        }
+
+        let turned = (position.yaw - self.yaw).rem_euclid(TAU);
+        self.movement != position.movement
+            || turned.min(TAU - turned) > YAW_TOLERANCE
+            || (self.speed - position.speed).abs() > f32::EPSILON
+    }
+
+    pub fn refresh(&mut self, position: &NpcPosition, now: Timestamp) {
+        self.cell = cell_at(position.x, position.z);
+        self.x = position.x;
+        self.y = position.y;
+        self.z = position.z;
+        self.yaw = position.yaw;
+        self.movement = position.movement;
+        self.speed = position.speed;
+        self.updated_at = now;
+        self.sampled_at = position.last_update;
+    }
+}
+
+#[table(name = npcs_ai_ticks, private, scheduled(tick_npcs))]
+pub struct NpcAiTick {
+    #[primary_key]
+    pub id: u64,
+    pub scheduled_at: ScheduleAt,
+}
+
+// Area kept populated with NPCs of one type, edited at runtime by the admins
+#[table(name = spawn_regions, public)]
+#[derive(Clone)]
+pub struct SpawnRegion {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub name: String,
+    // Bounds of the area NPCs spawn in
+    pub min_x: f32,
+    pub min_z: f32,
+    pub max_x: f32,
+    pub max_z: f32,
+    pub npc_type_id: u32,
+    pub max_alive: u32,
+    // Time a killed NPC takes to be replaced
+    pub respawn_delay_ms: u64,
+}
+
+impl SpawnRegion {
+    pub fn contains(&self, x: f32, z: f32) -> bool {
+        (self.min_x..=self.max_x).contains(&x) && (self.min_z..=self.max_z).contains(&z)
+    }
+}
+
+// NPC of a region waiting to be replaced, the region counts it as alive until then
+#[table(name = npcs_respawns)]
+pub struct NpcRespawn {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub region_id: u64,
+    pub respawn_at: Timestamp,
+}
+
+#[table(name = spawn_regions_ticks, private, scheduled(populate_spawn_regions))]
+pub struct SpawnRegionTick {
+    #[primary_key]
+    pub id: u64,
+    pub scheduled_at: ScheduleAt,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn region() -> SpawnRegion {
+        SpawnRegion {
+            id: 1,
+            name: "Wolf Den".to_string(),
+            min_x: -10.0,
+            min_z: 5.0,
+            max_x: 10.0,
+            max_z: 15.0,
+            npc_type_id: NpcType::WOLF,
+            max_alive: 5,
+            respawn_delay_ms: 30_000,
+        }
+    }
+
+    #[test]
+    fn contains_points_inside_the_bounds() {
+        assert!(region().contains(0.0, 10.0));
+        assert!(region().contains(-9.5, 14.5));
+    }
+
+    #[test]
+    fn bounds_are_inclusive() {
+        let region = region();
+        assert!(region.contains(region.min_x, region.min_z));
+        assert!(region.contains(region.max_x, region.max_z));
+    }
+
+    #[test]
+    fn excludes_points_outside_the_bounds() {
+        let region = region();
+        assert!(!region.contains(10.5, 10.0));
+        assert!(!region.contains(0.0, 4.9));
+        assert!(!region.contains(-11.0, 16.0));
+        assert!(!region.contains(f32::NAN, 10.0));
+    }
+}
@@ -1,32 +1,83 @@
-use spacetimedb::{table, Identity};
+use spacetimedb::{table, ScheduleAt, Timestamp};
 
+use crate::{
+    combat::{regenerate_players, respawn_player},
+    tables::players_positions::PlayerPosition,
+};
+
+// Stats of a character without equipment
+pub const BASE_MAX_HEALTH: f32 = 100.0;
+pub const BASE_MAX_MANA: f32 = 100.0;
+
+// In world state of a character, saved here while the character is offline
 #[table(name = players, public)]
 pub struct Player {
+    // Id of the character
     #[primary_key]
-    pub id: Identity,
+    pub id: u64,
+    // Name of the character, copied here since other accounts can't see the characters table
+    pub name: String,
     pub health: f32,
     pub max_health: f32,
     pub mana: f32,
     pub max_mana: f32,
+    // Derived from the equipment by recompute_stats, scales the movement speed
+    pub speed_multiplier: f32,
+    // Added to the damage of every ability the player casts
+    pub bonus_damage: f32,
     pub x: f32,
     pub y: f32,
     pub z: f32,
     #[index(btree)]
     pub online: bool,
+    // Killed and waiting to respawn, dead players can't move nor cast
+    pub dead: bool,
+    // Last time the player cast or was hit by an ability, regeneration is faster out of combat
+    pub last_combat: Timestamp,
 }
 
 impl Player {
-    pub fn new(id: Identity) -> Self {
+    pub fn new(id: u64, name: String) -> Self {
         Self {
             id,
-            health: 100.0,
-            max_health: 100.0,
-            mana: 100.0,
-            max_mana: 100.0,
+            name,
+            health: BASE_MAX_HEALTH,
+            max_health: BASE_MAX_HEALTH,
+            mana: BASE_MAX_MANA,
+            max_mana: BASE_MAX_MANA,
+            speed_multiplier: 1.0,
+            bonus_damage: 0.0,
             x: 0.0,
             y: 0.0,
             z: 0.0,
-            online: true,
+            online: false,
+            dead: false,
+            last_combat: Timestamp::UNIX_EPOCH,
         }
     }
+
+    // Copy the hot position of the player to cold storage
+    pub fn save_position(&mut self, position: &PlayerPosition) {
+        self.x = position.x;
+        self.y = position.y;
+        self.z = position.z;
+    }
+}
+
+// Brings a dead player back at the spawn point once the respawn delay elapsed
+#[table(name = players_respawns, private, scheduled(respawn_player))]
+pub struct PlayerRespawn {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub player_id: u64,
+    pub scheduled_at: ScheduleAt,
+}
+
+#[table(name = players_regen_ticks, private, scheduled(regenerate_players))]
+pub struct PlayerRegenTick {
+    #[primary_key]
+    pub id: u64,
+    pub scheduled_at: ScheduleAt,
 }
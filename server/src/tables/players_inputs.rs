@@ -0,0 +1,46 @@
+use spacetimedb::{table, ScheduleAt, Timestamp};
+
+use crate::players::simulate_players;
+
+// Inputs sent by a client, queued until the next simulation tick
+#[table(name = players_inputs)]
+#[derive(Clone, Copy)]
+pub struct PlayerInput {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub player_id: u64,
+    pub seq: u32,
+    // World space direction of the move, its length is at most 1
+    pub direction_x: f32,
+    pub direction_z: f32,
+    // Facing of the player, in radians around the vertical axis
+    pub yaw: f32,
+    pub run: bool,
+    pub jump: bool,
+    // Duration of the client frame the input was sampled for, in seconds
+    pub dt: f32,
+}
+
+// Last input applied by the server and the state it resulted in, the client replays
+// its inputs sent after `seq` on top of it
+#[table(name = players_inputs_acks, public)]
+#[derive(Clone, Copy)]
+pub struct PlayerInputAck {
+    #[primary_key]
+    pub player_id: u64,
+    pub seq: u32,
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub velocity_y: f32,
+    pub acked_at: Timestamp,
+}
+
+#[table(name = players_simulation_ticks, private, scheduled(simulate_players))]
+pub struct PlayerSimulationTick {
+    #[primary_key]
+    pub id: u64,
+    pub scheduled_at: ScheduleAt,
+}
@@ -1,25 +1,275 @@
-use spacetimedb::{table, Identity};
+use std::{f32::consts::TAU, time::Duration};
 
-#[table(name = players_positions, public)]
-#[table(name = players_positions_lr, public)]
+use spacetimedb::{table, ScheduleAt, SpacetimeType, Timestamp};
+
+use crate::{
+    consistency::report_consistency,
+    grid::cell_at,
+    players::{autosave_players, flush_positions_lods},
+    tables::{config::LodRing, players::Player},
+};
+
+// Facing changes smaller than this angle, in radians, don't refresh the snapshots
+const YAW_TOLERANCE: f32 = 0.05;
+
+// What the player is doing, drives the animations played by the other clients
+#[derive(SpacetimeType, Clone, Copy, PartialEq, Debug)]
+pub enum MovementState {
+    Standing,
+    Walking,
+    Running,
+    Jumping,
+    Falling,
+}
+
+// Authoritative position of the players, only visible to clients through players_positions_lods
+#[table(name = players_positions)]
 #[derive(Clone, Copy)]
 pub struct PlayerPosition {
+    // Id of the character
     #[primary_key]
-    pub id: Identity,
+    pub id: u64,
     pub x: f32,
     pub y: f32,
     pub z: f32,
-    pub update_count: u8,
+    // Rotation around the vertical axis, in radians
+    pub yaw: f32,
+    pub movement: MovementState,
+    // Horizontal speed, in units per second
+    pub speed: f32,
+    // Vertical speed of the player while simulated from its inputs
+    pub velocity_y: f32,
+    // Copied from the player's derived stats, scales the walk and run speeds
+    pub speed_multiplier: f32,
+    // Time of the last accepted move, used to bound the distance of the next one
+    pub last_update: Timestamp,
+    // Distance left over from the previous moves, see validate_move
+    pub move_budget: f32,
+    // Simulated time left over from the previous ticks, see input_time_budget
+    pub input_budget: f32,
+    // Moved since the last autosave to the players table
+    #[index(btree)]
+    pub dirty: bool,
 }
 
 impl PlayerPosition {
-    pub fn new(id: Identity, x: f32, y: f32, z: f32) -> Self {
+    // Hot position of a player entering the world where it was last saved
+    pub fn from_player(player: &Player, now: Timestamp) -> Self {
+        Self::new(
+            player.id,
+            player.x,
+            player.y,
+            player.z,
+            player.speed_multiplier,
+            now,
+        )
+    }
+
+    pub fn new(id: u64, x: f32, y: f32, z: f32, speed_multiplier: f32, now: Timestamp) -> Self {
         Self {
             id,
             x,
             y,
             z,
-            update_count: 0,
+            yaw: 0.0,
+            movement: MovementState::Standing,
+            speed: 0.0,
+            velocity_y: 0.0,
+            speed_multiplier,
+            last_update: now,
+            move_budget: 0.0,
+            input_budget: 0.0,
+            dirty: false,
         }
     }
 }
+
+// Snapshot of a player's position refreshed at the rate of its LOD ring
+#[table(name = players_positions_lods, public)]
+#[derive(Clone, Copy)]
+pub struct PlayerPositionLod {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub player_id: u64,
+    pub ring: u8,
+    #[index(btree)]
+    pub cell: u64,
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub yaw: f32,
+    pub movement: MovementState,
+    pub speed: f32,
+    pub updated_at: Timestamp,
+    // Time the position was reached on the server, clients interpolate between snapshots with it
+    pub sampled_at: Timestamp,
+}
+
+impl PlayerPositionLod {
+    pub fn new(ring: u8, position: &PlayerPosition, now: Timestamp) -> Self {
+        Self {
+            id: 0,
+            player_id: position.id,
+            ring,
+            cell: cell_at(position.x, position.z),
+            x: position.x,
+            y: position.y,
+            z: position.z,
+            yaw: position.yaw,
+            movement: position.movement,
+            speed: position.speed,
+            updated_at: now,
+            sampled_at: position.last_update,
+        }
+    }
+
+    // Squared distance between the snapshot and the authoritative position
+    fn distance_squared(&self, position: &PlayerPosition) -> f32 {
+        let dx = position.x - self.x;
+        let dy = position.y - self.y;
+        let dz = position.z - self.z;
+        dx * dx + dy * dy + dz * dz
+    }
+
+    // Whether the player turned or changed what it is doing since the snapshot
+    fn state_changed(&self, position: &PlayerPosition) -> bool {
+        let turned = (position.yaw - self.yaw).rem_euclid(TAU);
+        self.movement != position.movement
+            || turned.min(TAU - turned) > YAW_TOLERANCE
+            || (self.speed - position.speed).abs() > f32::EPSILON
+    }
+
+    // Whether the snapshot can be refreshed at the rate of its ring. Flushing ignores the
+    // distance so stopped players are not left behind
+    pub fn is_due(
+        &self,
+        position: &PlayerPosition,
+        ring: &LodRing,
+        now: Timestamp,
+        flush: bool,
+    ) -> bool {
+        let elapsed = now.duration_since(self.updated_at).unwrap_or_default();
+        if elapsed < Duration::from_millis(ring.update_interval_ms) {
+            return false;
+        }
+
+        let moved = self.distance_squared(position);
+        let min_distance = if flush { 0.0 } else { ring.min_distance };
+        moved > min_distance * min_distance || self.state_changed(position)
+    }
+
+    pub fn refresh(&mut self, position: &PlayerPosition, now: Timestamp) {
+        self.cell = cell_at(position.x, position.z);
+        self.x = position.x;
+        self.y = position.y;
+        self.z = position.z;
+        self.yaw = position.yaw;
+        self.movement = position.movement;
+        self.speed = position.speed;
+        self.updated_at = now;
+        self.sampled_at = position.last_update;
+    }
+}
+
+#[table(name = players_lods_flushes, private, scheduled(flush_positions_lods))]
+pub struct PlayerPositionLodFlush {
+    #[primary_key]
+    pub id: u64,
+    pub scheduled_at: ScheduleAt,
+}
+
+#[table(name = players_autosaves, private, scheduled(autosave_players))]
+pub struct PlayerAutosave {
+    #[primary_key]
+    pub id: u64,
+    pub scheduled_at: ScheduleAt,
+}
+
+impl PlayerAutosave {
+    // The schedule is a single row, replaced when the interval changes
+    pub const ID: u64 = 1;
+}
+
+#[table(name = consistency_checks, private, scheduled(report_consistency))]
+pub struct ConsistencyCheck {
+    #[primary_key]
+    pub id: u64,
+    pub scheduled_at: ScheduleAt,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RING: LodRing = LodRing {
+        ring: 1,
+        size: 64.0,
+        update_interval_ms: 1000,
+        min_distance: 2.0,
+    };
+
+    fn at_ms(ms: i64) -> Timestamp {
+        Timestamp::from_micros_since_unix_epoch(ms * 1000)
+    }
+
+    // Snapshot taken at the origin, along with the position it was taken from
+    fn snapshot_at_origin() -> (PlayerPositionLod, PlayerPosition) {
+        let position = PlayerPosition::new(1, 0.0, 0.0, 0.0, 1.0, at_ms(0));
+        (
+            PlayerPositionLod::new(RING.ring, &position, at_ms(0)),
+            position,
+        )
+    }
+
+    #[test]
+    fn waits_for_the_ring_interval() {
+        let (snapshot, mut position) = snapshot_at_origin();
+        position.x = 10.0;
+
+        assert!(!snapshot.is_due(&position, &RING, at_ms(500), false));
+        assert!(!snapshot.is_due(&position, &RING, at_ms(500), true));
+        assert!(snapshot.is_due(&position, &RING, at_ms(1000), false));
+    }
+
+    #[test]
+    fn waits_for_the_ring_min_distance() {
+        let (snapshot, mut position) = snapshot_at_origin();
+        position.x = 1.0;
+        assert!(!snapshot.is_due(&position, &RING, at_ms(1000), false));
+
+        position.x = 3.0;
+        assert!(snapshot.is_due(&position, &RING, at_ms(1000), false));
+    }
+
+    #[test]
+    fn refreshes_players_changing_state_in_place() {
+        let (snapshot, mut position) = snapshot_at_origin();
+        position.movement = MovementState::Jumping;
+        assert!(snapshot.is_due(&position, &RING, at_ms(1000), false));
+
+        let (snapshot, mut position) = snapshot_at_origin();
+        position.yaw = 1.0;
+        assert!(snapshot.is_due(&position, &RING, at_ms(1000), false));
+    }
+
+    #[test]
+    fn ignores_small_turns_across_a_full_turn() {
+        let (mut snapshot, mut position) = snapshot_at_origin();
+        snapshot.yaw = 0.01;
+        position.yaw = TAU - 0.01;
+
+        assert!(!snapshot.is_due(&position, &RING, at_ms(1000), false));
+    }
+
+    #[test]
+    fn flushes_ignore_the_min_distance() {
+        let (snapshot, mut position) = snapshot_at_origin();
+        assert!(!snapshot.is_due(&position, &RING, at_ms(1000), true));
+
+        position.x = 1.0;
+        assert!(!snapshot.is_due(&position, &RING, at_ms(1000), false));
+        assert!(snapshot.is_due(&position, &RING, at_ms(1000), true));
+    }
+}
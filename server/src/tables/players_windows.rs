@@ -1,62 +1,116 @@
 use spacetimedb::{table, Identity, ScheduleAt};
 
-use crate::players::update_players_windows;
+use crate::{
+    grid::{cell_at, cell_radius, pack_cell, unpack_cell},
+    players::update_players_windows,
+    tables::config::{LodRing, ServerConfig},
+};
 
 #[table(name = players_windows, public)]
 #[derive(Clone, Copy)]
 pub struct PlayerWindow {
+    // Id of the character
     #[primary_key]
-    pub id: Identity,
-    pub lr_bl_x: f32,
-    pub lr_bl_z: f32,
-    pub lr_tr_x: f32,
-    pub lr_tr_z: f32,
-    pub lr_size: f32,
-    pub hr_bl_x: f32,
-    pub hr_bl_z: f32,
-    pub hr_tr_x: f32,
-    pub hr_tr_z: f32,
-    pub hr_size: f32,
+    pub id: u64,
+    // Connection the window is sent to
+    pub identity: Identity,
+    pub center_x: f32,
+    pub center_z: f32,
+    // Ring sizes are scaled by the ratio between the view distance and the default one
+    pub view_distance: f32,
     pub recompute_distance: f32,
 }
 
 impl PlayerWindow {
-    pub fn new(id: Identity, x: f32, y: f32, lr_size: f32, hr_size: f32) -> Self {
-        let half_lr_size = lr_size / 2.0;
-        let half_hr_size = hr_size / 2.0;
-
-        let recompute_threshold = hr_size / 8.0;
-        Self {
+    pub fn new(
+        id: u64,
+        identity: Identity,
+        x: f32,
+        z: f32,
+        view_distance: f32,
+        config: &ServerConfig,
+        rings: &[LodRing],
+    ) -> Self {
+        let mut window = Self {
             id,
-            lr_bl_x: x - half_lr_size,
-            lr_bl_z: y - half_lr_size,
-            lr_tr_x: x + half_lr_size,
-            lr_tr_z: y + half_lr_size,
-            lr_size,
-            hr_bl_x: x - half_hr_size,
-            hr_bl_z: y - half_hr_size,
-            hr_tr_x: x + half_hr_size,
-            hr_tr_z: y + half_hr_size,
-            hr_size,
-            recompute_distance: recompute_threshold * recompute_threshold,
-        }
+            identity,
+            center_x: x,
+            center_z: z,
+            view_distance,
+            recompute_distance: 0.0,
+        };
+        window.resize(view_distance, config, rings);
+        window
+    }
+
+    // Whether the player drifted far enough from the window center for the window to follow
+    pub fn should_recompute(&self, x: f32, z: f32) -> bool {
+        let dx = x - self.center_x;
+        let dz = z - self.center_z;
+        dx * dx + dz * dz >= self.recompute_distance
     }
 
-    // Recompute the window boundaries based on the new position
+    // Recompute the window center based on the new position
     pub fn recompute(&mut self, x: f32, z: f32) {
-        let half_lr_size = self.lr_size / 2.0;
-        let half_hr_size = self.hr_size / 2.0;
+        self.center_x = x;
+        self.center_z = z;
+    }
+
+    // Resize the window, the recompute distance follows the size of the innermost ring
+    pub fn resize(&mut self, view_distance: f32, config: &ServerConfig, rings: &[LodRing]) {
+        self.view_distance = view_distance;
 
-        self.lr_bl_x = x - half_lr_size;
-        self.lr_bl_z = z - half_lr_size;
-        self.lr_tr_x = x + half_lr_size;
-        self.lr_tr_z = z + half_lr_size;
+        let innermost_size = rings
+            .first()
+            .map(|ring| self.ring_size(ring, config))
+            .unwrap_or(view_distance);
+        let recompute_threshold = innermost_size / 8.0;
+        self.recompute_distance = recompute_threshold * recompute_threshold;
+    }
 
-        self.hr_bl_x = x - half_hr_size;
-        self.hr_bl_z = z - half_hr_size;
-        self.hr_tr_x = x + half_hr_size;
-        self.hr_tr_z = z + half_hr_size;
+    pub fn ring_size(&self, ring: &LodRing, config: &ServerConfig) -> f32 {
+        ring.size * self.view_distance / config.default_view_distance
     }
+
+    // List the cells covered by the window along with the ring they belong to,
+    // a cell only belongs to the innermost ring covering it
+    pub fn cells(&self, config: &ServerConfig, rings: &[LodRing]) -> Vec<(u64, u8)> {
+        let (center_x, center_z) = unpack_cell(cell_at(self.center_x, self.center_z));
+        let radiuses: Vec<(i32, u8)> = rings
+            .iter()
+            .map(|ring| (cell_radius(self.ring_size(ring, config)), ring.ring))
+            .collect();
+        let Some(max_radius) = radiuses.iter().map(|(radius, _)| *radius).max() else {
+            return Vec::new();
+        };
+
+        let mut cells = Vec::with_capacity(((max_radius * 2 + 1) * (max_radius * 2 + 1)) as usize);
+        for dx in -max_radius..=max_radius {
+            for dz in -max_radius..=max_radius {
+                let distance = dx.abs().max(dz.abs());
+                if let Some((_, ring)) = radiuses.iter().find(|(radius, _)| distance <= *radius) {
+                    cells.push((pack_cell(center_x + dx, center_z + dz), *ring));
+                }
+            }
+        }
+
+        cells
+    }
+}
+
+#[table(name = players_windows_cells, public)]
+pub struct PlayerWindowCell {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub player_id: u64,
+    // Connection of the player, the visibility filters resolve the sender's cells with it
+    #[index(btree)]
+    pub identity: Identity,
+    #[index(btree)]
+    pub cell: u64,
+    pub ring: u8,
 }
 
 #[table(name = players_window_updates, private, scheduled(update_players_windows))]
@@ -0,0 +1,106 @@
+use std::time::Duration;
+
+use spacetimedb::{
+    log_stopwatch::LogStopwatch, reducer, ReducerContext, Table, TimeDuration, Timestamp,
+};
+
+use crate::{
+    admin::ensure_scheduler,
+    characters::current_character,
+    combat::ensure_alive,
+    grid::cell_at,
+    inventory::{give_item, item_definition},
+    tables::{
+        config::ServerConfig,
+        items::{world_items, WorldItem, WorldItemDespawn},
+        players_positions::players_positions,
+    },
+};
+
+#[reducer]
+fn pick_up_item(ctx: &ReducerContext, world_item_id: u64) -> Result<(), String> {
+    let character_id = current_character(ctx)?;
+    ensure_alive(ctx, character_id)?;
+
+    let item = ctx
+        .db
+        .world_items()
+        .id()
+        .find(world_item_id)
+        .ok_or("The item is gone")?;
+    let position = ctx
+        .db
+        .players_positions()
+        .id()
+        .find(character_id)
+        .ok_or("Not in the world")?;
+
+    let config = ServerConfig::get(ctx);
+    let dx = item.x - position.x;
+    let dy = item.y - position.y;
+    let dz = item.z - position.z;
+    if dx * dx + dy * dy + dz * dz > config.pickup_range * config.pickup_range {
+        return Err("The item is out of reach".to_string());
+    }
+    if item
+        .loot_owner
+        .is_some_and(|owner| owner != character_id && item.loot_locked_until > ctx.timestamp)
+    {
+        let definition = item_definition(ctx, item.item_id)?;
+        return Err(format!("{} belongs to someone else", definition.name));
+    }
+
+    give_item(ctx, character_id, item.item_id, item.quantity)?;
+    ctx.db.world_items().id().delete(item.id);
+    Ok(())
+}
+
+#[reducer]
+pub fn despawn_world_items(ctx: &ReducerContext, _row: WorldItemDespawn) -> Result<(), String> {
+    ensure_scheduler(ctx)?;
+    let _stopwatch = LogStopwatch::new("despawn_world_items");
+
+    let expired: Vec<u64> = ctx
+        .db
+        .world_items()
+        .iter()
+        .filter(|item| item.despawn_at <= ctx.timestamp)
+        .map(|item| item.id)
+        .collect();
+    for id in &expired {
+        ctx.db.world_items().id().delete(id);
+    }
+
+    if !expired.is_empty() {
+        log::info!("despawn_world_items: despawned {} items", expired.len());
+    }
+
+    Ok(())
+}
+
+// Put items on the ground, reserved to the loot owner for a while when there is one
+pub fn spawn_world_item(
+    ctx: &ReducerContext,
+    item_id: u32,
+    quantity: u32,
+    (x, y, z): (f32, f32, f32),
+    loot_owner: Option<u64>,
+) {
+    let config = ServerConfig::get(ctx);
+    ctx.db.world_items().insert(WorldItem {
+        id: 0,
+        item_id,
+        quantity,
+        x,
+        y,
+        z,
+        cell: cell_at(x, z),
+        loot_owner,
+        loot_locked_until: after(ctx.timestamp, config.loot_lock_ms),
+        despawn_at: after(ctx.timestamp, config.world_item_lifetime_ms),
+    });
+}
+
+fn after(timestamp: Timestamp, ms: u64) -> Timestamp {
+    timestamp + TimeDuration::from_duration(Duration::from_millis(ms))
+}